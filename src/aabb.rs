@@ -0,0 +1,233 @@
+use crate::{Ray, RayHit, UnitVector3, Vector3};
+
+/// An axis-aligned bounding box, treated as a solid.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Aabb {
+    pub min: Vector3,
+    pub max: Vector3,
+}
+
+impl Aabb {
+    /// The box containing nothing; the identity for `union` and `grow`.
+    pub const EMPTY: Self = Self {
+        min: Vector3 {
+            x: f64::INFINITY,
+            y: f64::INFINITY,
+            z: f64::INFINITY,
+        },
+        max: Vector3 {
+            x: f64::NEG_INFINITY,
+            y: f64::NEG_INFINITY,
+            z: f64::NEG_INFINITY,
+        },
+    };
+
+    /// The box spanned by two opposite corners, in any order.
+    pub fn new(a: Vector3, b: Vector3) -> Self {
+        Self {
+            min: a.min(b),
+            max: a.max(b),
+        }
+    }
+
+    /// The smallest box containing all `points`, or `EMPTY` if there are none.
+    pub fn from_points(points: impl IntoIterator<Item = Vector3>) -> Self {
+        points.into_iter().fold(Self::EMPTY, Self::grow)
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.min.x > self.max.x || self.min.y > self.max.y || self.min.z > self.max.z
+    }
+
+    pub fn center(self) -> Vector3 {
+        (self.min + self.max) * 0.5
+    }
+
+    /// The edge lengths along each axis.
+    pub fn extents(self) -> Vector3 {
+        self.max - self.min
+    }
+
+    pub fn surface_area(self) -> f64 {
+        if self.is_empty() {
+            return 0.0;
+        }
+        let e = self.extents();
+        2.0 * (e.x * e.y + e.y * e.z + e.z * e.x)
+    }
+
+    pub fn grow(self, point: Vector3) -> Self {
+        Self {
+            min: self.min.min(point),
+            max: self.max.max(point),
+        }
+    }
+
+    pub fn union(self, other: Self) -> Self {
+        Self {
+            min: self.min.min(other.min),
+            max: self.max.max(other.max),
+        }
+    }
+
+    pub fn overlaps(self, other: Self) -> bool {
+        self.min.x <= other.max.x
+            && self.max.x >= other.min.x
+            && self.min.y <= other.max.y
+            && self.max.y >= other.min.y
+            && self.min.z <= other.max.z
+            && self.max.z >= other.min.z
+    }
+
+    pub fn contains(self, point: Vector3) -> bool {
+        self.min.x <= point.x
+            && point.x <= self.max.x
+            && self.min.y <= point.y
+            && point.y <= self.max.y
+            && self.min.z <= point.z
+            && point.z <= self.max.z
+    }
+
+    /// The nearest point of the box, which is `point` itself if inside.
+    pub fn closest_point(self, point: Vector3) -> Vector3 {
+        point.max(self.min).min(self.max)
+    }
+
+    /// Distance to the surface; negative inside.
+    pub fn signed_distance(self, point: Vector3) -> f64 {
+        let half = self.extents() * 0.5;
+        let p = point - self.center();
+        let q = Vector3::new(p.x.abs(), p.y.abs(), p.z.abs()) - half;
+        let outside = q.max(Vector3::ZERO).length();
+        let inside = q.x.max(q.y).max(q.z).min(0.0);
+        outside + inside
+    }
+
+    /// The first point where the ray crosses the surface, including exits
+    /// for rays that start inside.
+    pub fn intersect_ray(self, ray: Ray) -> Option<RayHit> {
+        let (t_enter, enter_axis, t_exit, exit_axis) = self.slabs(ray)?;
+        let (t, axis, sign) = if t_enter >= 0.0 {
+            (t_enter, enter_axis, -1.0)
+        } else {
+            (t_exit, exit_axis, 1.0)
+        };
+        let mut normal = Vector3::ZERO;
+        normal[axis] = sign * ray.direction[axis].signum();
+        Some(ray.hit(t, UnitVector3::new_unchecked(normal)))
+    }
+
+    /// The range of ray distances inside the box, clipped to start at zero.
+    pub(crate) fn ray_interval(self, ray: Ray) -> Option<(f64, f64)> {
+        let (t_enter, _, t_exit, _) = self.slabs(ray)?;
+        Some((t_enter.max(0.0), t_exit))
+    }
+
+    /// Slab test, returning the entry and exit distances and the axes of the
+    /// faces crossed there. Comparisons against NaN are false, so a ray lying
+    /// in a slab's boundary plane leaves the interval unchanged.
+    fn slabs(self, ray: Ray) -> Option<(f64, usize, f64, usize)> {
+        let (mut t_enter, mut enter_axis) = (f64::NEG_INFINITY, 0);
+        let (mut t_exit, mut exit_axis) = (f64::INFINITY, 0);
+        for axis in 0..3 {
+            let inverse = 1.0 / ray.direction[axis];
+            let t0 = (self.min[axis] - ray.origin[axis]) * inverse;
+            let t1 = (self.max[axis] - ray.origin[axis]) * inverse;
+            let (near, far) = if t0 > t1 { (t1, t0) } else { (t0, t1) };
+            if near > t_enter {
+                t_enter = near;
+                enter_axis = axis;
+            }
+            if far < t_exit {
+                t_exit = far;
+                exit_axis = axis;
+            }
+        }
+        if t_exit < t_enter || t_exit < 0.0 {
+            return None;
+        }
+        Some((t_enter, enter_axis, t_exit, exit_axis))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn unit_box() -> Aabb {
+        Aabb::new(Vector3::new(1.0, 1.0, 1.0), Vector3::new(-1.0, -1.0, -1.0))
+    }
+
+    #[test]
+    fn test_aabb_construction() {
+        let b = unit_box();
+        assert_eq!(b.min, Vector3::new(-1.0, -1.0, -1.0));
+        assert_eq!(b.max, Vector3::new(1.0, 1.0, 1.0));
+        assert_eq!(b.center(), Vector3::ZERO);
+        assert_eq!(b.surface_area(), 24.0);
+
+        let points = [Vector3::new(1.0, 5.0, 0.0), Vector3::new(-2.0, 0.0, 3.0)];
+        let b = Aabb::from_points(points);
+        assert_eq!(b.min, Vector3::new(-2.0, 0.0, 0.0));
+        assert_eq!(b.max, Vector3::new(1.0, 5.0, 3.0));
+        assert!(Aabb::from_points([]).is_empty());
+        assert_eq!(Aabb::EMPTY.union(b), b);
+        assert_eq!(Aabb::EMPTY.surface_area(), 0.0);
+    }
+
+    #[test]
+    fn test_aabb_point_queries() {
+        let b = unit_box();
+        assert!(b.contains(Vector3::new(0.5, -1.0, 0.0)));
+        assert!(!b.contains(Vector3::new(0.5, -1.5, 0.0)));
+        assert_eq!(
+            b.closest_point(Vector3::new(3.0, 0.5, -4.0)),
+            Vector3::new(1.0, 0.5, -1.0)
+        );
+        assert_eq!(b.signed_distance(Vector3::new(3.0, 0.0, 0.0)), 2.0);
+        assert_eq!(b.signed_distance(Vector3::new(4.0, 5.0, 0.0)), 5.0);
+        assert_eq!(b.signed_distance(Vector3::new(0.25, 0.0, 0.0)), -0.75);
+    }
+
+    #[test]
+    fn test_aabb_overlaps() {
+        let b = unit_box();
+        let c = Aabb::new(Vector3::new(1.0, 0.0, 0.0), Vector3::new(2.0, 2.0, 2.0));
+        let d = Aabb::new(Vector3::new(1.5, 0.0, 0.0), Vector3::new(2.0, 2.0, 2.0));
+        assert!(b.overlaps(c));
+        assert!(!b.overlaps(d));
+    }
+
+    #[test]
+    fn test_aabb_intersect_ray() {
+        let b = unit_box();
+        let ray = Ray::new(Vector3::new(-5.0, 0.5, 0.0), UnitVector3::X);
+        let hit = b.intersect_ray(ray).unwrap();
+        assert_eq!(hit.distance, 4.0);
+        assert_eq!(hit.point, Vector3::new(-1.0, 0.5, 0.0));
+        assert_eq!(*hit.normal, Vector3::new(-1.0, 0.0, 0.0));
+
+        // From inside, the exit is reported with the outward normal.
+        let hit = b
+            .intersect_ray(Ray::new(Vector3::ZERO, UnitVector3::Y))
+            .unwrap();
+        assert_eq!(hit.distance, 1.0);
+        assert_eq!(hit.normal, UnitVector3::Y);
+
+        // Along a face.
+        let ray = Ray::new(Vector3::new(-5.0, 1.0, 0.0), UnitVector3::X);
+        assert_eq!(b.intersect_ray(ray).unwrap().distance, 4.0);
+
+        // Misses: parallel outside, and box behind.
+        let ray = Ray::new(Vector3::new(-5.0, 2.0, 0.0), UnitVector3::X);
+        assert_eq!(b.intersect_ray(ray), None);
+        let ray = Ray::new(Vector3::new(5.0, 0.0, 0.0), UnitVector3::X);
+        assert_eq!(b.intersect_ray(ray), None);
+
+        let diagonal = UnitVector3::try_new(Vector3::new(1.0, 1.0, 1.0)).unwrap();
+        let hit = b
+            .intersect_ray(Ray::new(Vector3::new(-3.0, -3.0, -2.0), diagonal))
+            .unwrap();
+        assert_vec_approx_eq!(hit.point, Vector3::new(-1.0, -1.0, 0.0), epsilon = 1e-12);
+    }
+}
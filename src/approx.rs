@@ -0,0 +1,421 @@
+use std::fmt::Debug;
+
+use crate::{
+    FramePoint3, FrameVector3, Matrix3, Matrix4, Point3, Quaternion, Scalar, UnitVector3, Vector2,
+    Vector3, Vector4,
+};
+
+/// Approximate equality for floating-point values and the types built from
+/// them. Composite types compare component by component.
+pub trait ApproxEq {
+    type Epsilon: Copy;
+
+    fn default_epsilon() -> Self::Epsilon;
+    fn default_max_relative() -> Self::Epsilon;
+    fn default_max_ulps() -> u32 {
+        4
+    }
+
+    /// True if every component differs by at most `epsilon`.
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool;
+
+    /// True if every component differs by at most `epsilon`, or by at most
+    /// `max_relative` times the larger magnitude of the two.
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool;
+
+    /// True if every component differs by at most `epsilon`, or is at most
+    /// `max_ulps` representable values away.
+    fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool;
+
+    /// The component-wise difference `self - other`, shown when
+    /// `assert_vec_approx_eq!` fails.
+    fn error(&self, other: &Self) -> impl Debug;
+}
+
+macro_rules! impl_approx_eq_float {
+    ($($t:ty),*) => {
+        $(
+            impl ApproxEq for $t {
+                type Epsilon = $t;
+
+                fn default_epsilon() -> $t {
+                    <$t>::EPSILON
+                }
+
+                fn default_max_relative() -> $t {
+                    <$t>::EPSILON
+                }
+
+                fn abs_diff_eq(&self, other: &$t, epsilon: $t) -> bool {
+                    self == other || (self - other).abs() <= epsilon
+                }
+
+                fn relative_eq(&self, other: &$t, epsilon: $t, max_relative: $t) -> bool {
+                    if self == other {
+                        return true;
+                    }
+                    if self.is_infinite() || other.is_infinite() {
+                        return false;
+                    }
+                    let diff = (self - other).abs();
+                    if diff <= epsilon {
+                        return true;
+                    }
+                    diff <= self.abs().max(other.abs()) * max_relative
+                }
+
+                fn ulps_eq(&self, other: &$t, epsilon: $t, max_ulps: u32) -> bool {
+                    if self.abs_diff_eq(other, epsilon) {
+                        return true;
+                    }
+                    if self.is_nan() || other.is_nan() || self.signum() != other.signum() {
+                        return false;
+                    }
+                    self.to_bits().abs_diff(other.to_bits()) <= max_ulps.into()
+                }
+
+                fn error(&self, other: &$t) -> impl Debug {
+                    self - other
+                }
+            }
+        )*
+    };
+}
+
+impl_approx_eq_float!(f32, f64);
+
+macro_rules! impl_approx_eq_fields {
+    ($($type:ident { $($field:ident),+ }),*) => {
+        $(
+            impl<T: Scalar + ApproxEq> ApproxEq for $type<T> {
+                type Epsilon = T::Epsilon;
+
+                fn default_epsilon() -> T::Epsilon {
+                    T::default_epsilon()
+                }
+
+                fn default_max_relative() -> T::Epsilon {
+                    T::default_max_relative()
+                }
+
+                fn default_max_ulps() -> u32 {
+                    T::default_max_ulps()
+                }
+
+                fn abs_diff_eq(&self, other: &Self, epsilon: T::Epsilon) -> bool {
+                    $(self.$field.abs_diff_eq(&other.$field, epsilon))&&+
+                }
+
+                fn relative_eq(
+                    &self,
+                    other: &Self,
+                    epsilon: T::Epsilon,
+                    max_relative: T::Epsilon,
+                ) -> bool {
+                    $(self.$field.relative_eq(&other.$field, epsilon, max_relative))&&+
+                }
+
+                fn ulps_eq(&self, other: &Self, epsilon: T::Epsilon, max_ulps: u32) -> bool {
+                    $(self.$field.ulps_eq(&other.$field, epsilon, max_ulps))&&+
+                }
+
+                fn error(&self, other: &Self) -> impl Debug {
+                    *self - *other
+                }
+            }
+        )*
+    };
+}
+
+impl_approx_eq_fields!(
+    Vector2 { x, y },
+    Vector3 { x, y, z },
+    Vector4 { x, y, z, w },
+    Point3 { x, y, z },
+    Matrix3 { cols },
+    Matrix4 { cols }
+);
+
+impl<T: ApproxEq, const N: usize> ApproxEq for [T; N] {
+    type Epsilon = T::Epsilon;
+
+    fn default_epsilon() -> T::Epsilon {
+        T::default_epsilon()
+    }
+
+    fn default_max_relative() -> T::Epsilon {
+        T::default_max_relative()
+    }
+
+    fn default_max_ulps() -> u32 {
+        T::default_max_ulps()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: T::Epsilon) -> bool {
+        self.iter()
+            .zip(other)
+            .all(|(a, b)| a.abs_diff_eq(b, epsilon))
+    }
+
+    fn relative_eq(&self, other: &Self, epsilon: T::Epsilon, max_relative: T::Epsilon) -> bool {
+        self.iter()
+            .zip(other)
+            .all(|(a, b)| a.relative_eq(b, epsilon, max_relative))
+    }
+
+    fn ulps_eq(&self, other: &Self, epsilon: T::Epsilon, max_ulps: u32) -> bool {
+        self.iter()
+            .zip(other)
+            .all(|(a, b)| a.ulps_eq(b, epsilon, max_ulps))
+    }
+
+    fn error(&self, other: &Self) -> impl Debug {
+        std::array::from_fn::<_, N, _>(|i| self[i].error(&other[i]))
+    }
+}
+
+impl<T: Scalar + ApproxEq> ApproxEq for UnitVector3<T> {
+    type Epsilon = T::Epsilon;
+
+    fn default_epsilon() -> T::Epsilon {
+        T::default_epsilon()
+    }
+
+    fn default_max_relative() -> T::Epsilon {
+        T::default_max_relative()
+    }
+
+    fn default_max_ulps() -> u32 {
+        T::default_max_ulps()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: T::Epsilon) -> bool {
+        (**self).abs_diff_eq(other, epsilon)
+    }
+
+    fn relative_eq(&self, other: &Self, epsilon: T::Epsilon, max_relative: T::Epsilon) -> bool {
+        (**self).relative_eq(other, epsilon, max_relative)
+    }
+
+    fn ulps_eq(&self, other: &Self, epsilon: T::Epsilon, max_ulps: u32) -> bool {
+        (**self).ulps_eq(other, epsilon, max_ulps)
+    }
+
+    fn error(&self, other: &Self) -> impl Debug {
+        (**self).error(other)
+    }
+}
+
+impl ApproxEq for Quaternion {
+    type Epsilon = f64;
+
+    fn default_epsilon() -> f64 {
+        f64::EPSILON
+    }
+
+    fn default_max_relative() -> f64 {
+        f64::EPSILON
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: f64) -> bool {
+        self.v.abs_diff_eq(&other.v, epsilon) && self.w.abs_diff_eq(&other.w, epsilon)
+    }
+
+    fn relative_eq(&self, other: &Self, epsilon: f64, max_relative: f64) -> bool {
+        self.v.relative_eq(&other.v, epsilon, max_relative)
+            && self.w.relative_eq(&other.w, epsilon, max_relative)
+    }
+
+    fn ulps_eq(&self, other: &Self, epsilon: f64, max_ulps: u32) -> bool {
+        self.v.ulps_eq(&other.v, epsilon, max_ulps) && self.w.ulps_eq(&other.w, epsilon, max_ulps)
+    }
+
+    fn error(&self, other: &Self) -> impl Debug {
+        *self - *other
+    }
+}
+
+// Frame-tagged types compare as their untagged forms.
+macro_rules! impl_approx_eq_tagged {
+    ($($ty:ident => $untag:ident),*) => {$(
+        impl<F, T: Scalar + ApproxEq> ApproxEq for $ty<F, T> {
+            type Epsilon = T::Epsilon;
+
+            fn default_epsilon() -> T::Epsilon {
+                T::default_epsilon()
+            }
+
+            fn default_max_relative() -> T::Epsilon {
+                T::default_max_relative()
+            }
+
+            fn default_max_ulps() -> u32 {
+                T::default_max_ulps()
+            }
+
+            fn abs_diff_eq(&self, other: &Self, epsilon: T::Epsilon) -> bool {
+                self.$untag().abs_diff_eq(&other.$untag(), epsilon)
+            }
+
+            fn relative_eq(
+                &self,
+                other: &Self,
+                epsilon: T::Epsilon,
+                max_relative: T::Epsilon,
+            ) -> bool {
+                self.$untag()
+                    .relative_eq(&other.$untag(), epsilon, max_relative)
+            }
+
+            fn ulps_eq(&self, other: &Self, epsilon: T::Epsilon, max_ulps: u32) -> bool {
+                self.$untag().ulps_eq(&other.$untag(), epsilon, max_ulps)
+            }
+
+            fn error(&self, other: &Self) -> impl Debug {
+                (*self - *other).to_vector()
+            }
+        }
+    )*};
+}
+
+impl_approx_eq_tagged!(FrameVector3 => to_vector, FramePoint3 => to_point);
+
+#[doc(hidden)]
+pub fn __default_epsilon<T: ApproxEq>(_: &T) -> T::Epsilon {
+    T::default_epsilon()
+}
+
+#[doc(hidden)]
+pub fn __default_max_relative<T: ApproxEq>(_: &T) -> T::Epsilon {
+    T::default_max_relative()
+}
+
+/// Asserts that two values are approximately equal, printing both and their
+/// difference on failure.
+///
+/// With no tolerance the default relative comparison is used. Otherwise pass
+/// one of `epsilon = e` (absolute), `max_relative = r` or `max_ulps = n`.
+#[macro_export]
+macro_rules! assert_vec_approx_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        $crate::assert_vec_approx_eq!(@check $left, $right, |a, b| {
+            $crate::ApproxEq::relative_eq(
+                a,
+                b,
+                $crate::__default_epsilon(a),
+                $crate::__default_max_relative(a),
+            )
+        })
+    };
+    ($left:expr, $right:expr, epsilon = $epsilon:expr $(,)?) => {
+        $crate::assert_vec_approx_eq!(@check $left, $right, |a, b| {
+            $crate::ApproxEq::abs_diff_eq(a, b, $epsilon)
+        })
+    };
+    ($left:expr, $right:expr, max_relative = $max_relative:expr $(,)?) => {
+        $crate::assert_vec_approx_eq!(@check $left, $right, |a, b| {
+            $crate::ApproxEq::relative_eq(
+                a,
+                b,
+                $crate::__default_epsilon(a),
+                $max_relative,
+            )
+        })
+    };
+    ($left:expr, $right:expr, max_ulps = $max_ulps:expr $(,)?) => {
+        $crate::assert_vec_approx_eq!(@check $left, $right, |a, b| {
+            $crate::ApproxEq::ulps_eq(a, b, $crate::__default_epsilon(a), $max_ulps)
+        })
+    };
+    (@check $left:expr, $right:expr, $eq:expr) => {
+        match (&$left, &$right) {
+            (left, right) => {
+                if !$eq(left, right) {
+                    panic!(
+                        "assertion `left ≈ right` failed\n  left: {:?}\n right: {:?}\n error: {:?}",
+                        left,
+                        right,
+                        $crate::ApproxEq::error(left, right),
+                    );
+                }
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_approx_eq_scalar() {
+        assert!(1.0f64.abs_diff_eq(&1.05, 0.1));
+        assert!(!1.0f64.abs_diff_eq(&1.2, 0.1));
+        assert!(1e10f64.relative_eq(&(1e10 + 1.0), 0.0, 1e-9));
+        assert!(!1e-10f64.relative_eq(&2e-10, 0.0, 1e-9));
+        assert!(1.0f64.ulps_eq(&(1.0 + 2.0 * f64::EPSILON), 0.0, 2));
+        assert!(!1.0f64.ulps_eq(&(1.0 + 8.0 * f64::EPSILON), 0.0, 4));
+        assert!(!f64::NAN.relative_eq(&f64::NAN, 1.0, 1.0));
+        assert!(f64::INFINITY.relative_eq(&f64::INFINITY, 0.0, 0.0));
+        assert!(!(-0.1f64).ulps_eq(&0.1, 0.0, 4));
+    }
+
+    #[test]
+    fn test_approx_eq_vector() {
+        let a = Vector3::new(0.1 + 0.2, 1.0, -2.0);
+        let b = Vector3::new(0.3, 1.0, -2.0);
+        assert_ne!(a, b);
+        assert!(a.ulps_eq(&b, 0.0, 1));
+        assert!(a.relative_eq(&b, 0.0, f64::EPSILON));
+        assert!(!a.abs_diff_eq(&Vector3::new(0.3, 1.0, -2.1), 0.05));
+        assert_vec_approx_eq!(a, b);
+        assert_vec_approx_eq!(a, b, epsilon = 1e-15);
+        assert_vec_approx_eq!(a, b, max_ulps = 1);
+        assert_vec_approx_eq!(Vector2::new(0.1f32 + 0.2, 1.0), Vector2::new(0.3, 1.0));
+    }
+
+    #[test]
+    fn test_approx_eq_matrix_quaternion() {
+        let m = Matrix3::from_diagonal(Vector3::new(3.0, 7.0, 11.0));
+        assert_vec_approx_eq!(m * m.inverse().unwrap(), Matrix3::IDENTITY);
+
+        let q = Quaternion::from_axis_angle(Vector3::new(0.0, 0.0, 1.0), 0.5);
+        assert_vec_approx_eq!(q * q.conjugate(), Quaternion::IDENTITY);
+    }
+
+    #[test]
+    #[should_panic(expected = "error: Vector3 { x: 0.0, y: -1.0, z: 0.0 }")]
+    fn test_assert_vec_approx_eq_failure() {
+        assert_vec_approx_eq!(Vector3::new(1.0, 1.0, 1.0), Vector3::new(1.0, 2.0, 1.0));
+    }
+
+    #[test]
+    fn test_approx_eq_unit_vector_array() {
+        let v = UnitVector3::try_new(Vector3::new(1.0, 1.0, 0.0)).unwrap();
+        let half = std::f64::consts::FRAC_1_SQRT_2;
+        assert_vec_approx_eq!(v, UnitVector3::new_unchecked(Vector3::new(half, half, 0.0)));
+        assert_vec_approx_eq!([0.1 + 0.2, 1.0], [0.3, 1.0]);
+        assert_vec_approx_eq!(
+            [Vector3::new(0.1 + 0.2, 0.0, 0.0), Vector3::ZERO],
+            [Vector3::new(0.3, 0.0, 0.0), Vector3::ZERO],
+            epsilon = 1e-15
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "error: [0.0, -0.5]")]
+    fn test_assert_vec_approx_eq_array_failure() {
+        assert_vec_approx_eq!([1.0, 1.5], [1.0, 2.0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "error: Vector3 { x: -1.0, y: 1.0, z: 0.0 }")]
+    fn test_assert_vec_approx_eq_unit_vector_failure() {
+        assert_vec_approx_eq!(UnitVector3::<f64>::Y, UnitVector3::X);
+    }
+}
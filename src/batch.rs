@@ -0,0 +1,353 @@
+use std::array;
+use std::ops::{Add, BitAnd, BitOr, Mul, Neg, Not, Sub};
+
+use crate::{Float, Scalar, Vector3};
+
+/// `N` vectors in structure-of-arrays layout, with the x, y and z components
+/// of all lanes stored contiguously.
+///
+/// Every lane-wise operation performs the same floating-point operations in
+/// the same order as the corresponding `Vector3` method, so results match
+/// the scalar path exactly.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[repr(C)]
+pub struct Vector3Batch<T, const N: usize> {
+    pub x: [T; N],
+    pub y: [T; N],
+    pub z: [T; N],
+}
+
+pub type Vector3x4<T = f64> = Vector3Batch<T, 4>;
+pub type Vector3x8<T = f64> = Vector3Batch<T, 8>;
+
+/// One boolean per lane, produced by lane-wise comparisons and consumed by
+/// `Vector3Batch::select`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Mask<const N: usize>(pub [bool; N]);
+
+impl<const N: usize> Mask<N> {
+    pub fn from_fn(f: impl FnMut(usize) -> bool) -> Self {
+        Self(array::from_fn(f))
+    }
+
+    pub fn all(self) -> bool {
+        self.0.iter().all(|&b| b)
+    }
+
+    pub fn any(self) -> bool {
+        self.0.iter().any(|&b| b)
+    }
+
+    pub fn none(self) -> bool {
+        !self.any()
+    }
+}
+
+impl<const N: usize> BitAnd for Mask<N> {
+    type Output = Self;
+
+    fn bitand(self, other: Self) -> Self {
+        Self::from_fn(|i| self.0[i] & other.0[i])
+    }
+}
+
+impl<const N: usize> BitOr for Mask<N> {
+    type Output = Self;
+
+    fn bitor(self, other: Self) -> Self {
+        Self::from_fn(|i| self.0[i] | other.0[i])
+    }
+}
+
+impl<const N: usize> Not for Mask<N> {
+    type Output = Self;
+
+    fn not(self) -> Self {
+        Self::from_fn(|i| !self.0[i])
+    }
+}
+
+impl<T: Scalar, const N: usize> Vector3Batch<T, N> {
+    pub const ZERO: Self = Self {
+        x: [T::ZERO; N],
+        y: [T::ZERO; N],
+        z: [T::ZERO; N],
+    };
+
+    /// Puts `v` in every lane.
+    pub fn splat(v: Vector3<T>) -> Self {
+        Self {
+            x: [v.x; N],
+            y: [v.y; N],
+            z: [v.z; N],
+        }
+    }
+
+    /// Gathers exactly `N` vectors.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `vectors.len() != N`.
+    pub fn from_slice(vectors: &[Vector3<T>]) -> Self {
+        assert_eq!(
+            vectors.len(),
+            N,
+            "Vector3Batch::from_slice needs exactly {N} vectors"
+        );
+        Self::from_fn(|i| vectors[i])
+    }
+
+    /// Scatters the lanes into exactly `N` vectors.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out.len() != N`.
+    pub fn write_to_slice(self, out: &mut [Vector3<T>]) {
+        assert_eq!(
+            out.len(),
+            N,
+            "Vector3Batch::write_to_slice needs exactly {N} vectors"
+        );
+        for (i, v) in out.iter_mut().enumerate() {
+            *v = self.lane(i);
+        }
+    }
+
+    pub fn to_array(self) -> [Vector3<T>; N] {
+        array::from_fn(|i| self.lane(i))
+    }
+
+    fn from_fn(mut f: impl FnMut(usize) -> Vector3<T>) -> Self {
+        let mut batch = Self::ZERO;
+        for i in 0..N {
+            batch.set_lane(i, f(i));
+        }
+        batch
+    }
+
+    pub fn lane(self, i: usize) -> Vector3<T> {
+        Vector3::new(self.x[i], self.y[i], self.z[i])
+    }
+
+    pub fn set_lane(&mut self, i: usize, v: Vector3<T>) {
+        self.x[i] = v.x;
+        self.y[i] = v.y;
+        self.z[i] = v.z;
+    }
+
+    /// Takes lanes from `if_true` where `mask` is set and from `if_false`
+    /// elsewhere.
+    pub fn select(mask: Mask<N>, if_true: Self, if_false: Self) -> Self {
+        Self::from_fn(|i| {
+            if mask.0[i] {
+                if_true.lane(i)
+            } else {
+                if_false.lane(i)
+            }
+        })
+    }
+
+    pub fn dot(self, other: Self) -> [T; N] {
+        array::from_fn(|i| self.x[i] * other.x[i] + self.y[i] * other.y[i] + self.z[i] * other.z[i])
+    }
+
+    pub fn cross(self, other: Self) -> Self {
+        Self {
+            x: array::from_fn(|i| self.y[i] * other.z[i] - self.z[i] * other.y[i]),
+            y: array::from_fn(|i| self.z[i] * other.x[i] - self.x[i] * other.z[i]),
+            z: array::from_fn(|i| self.x[i] * other.y[i] - self.y[i] * other.x[i]),
+        }
+    }
+
+    pub fn lanes_eq(self, other: Self) -> Mask<N> {
+        Mask::from_fn(|i| self.lane(i) == other.lane(i))
+    }
+}
+
+impl<T: Float, const N: usize> Vector3Batch<T, N> {
+    pub fn length(self) -> [T; N] {
+        self.dot(self).map(Float::sqrt)
+    }
+
+    /// Normalizes every lane without checks, like `Vector3::normalized`.
+    pub fn normalized(self) -> Self {
+        let length = self.length();
+        Self {
+            x: array::from_fn(|i| self.x[i] / length[i]),
+            y: array::from_fn(|i| self.y[i] / length[i]),
+            z: array::from_fn(|i| self.z[i] / length[i]),
+        }
+    }
+
+    pub fn is_finite(self) -> Mask<N> {
+        Mask::from_fn(|i| self.lane(i).is_finite())
+    }
+}
+
+impl<T: Scalar, const N: usize> From<[Vector3<T>; N]> for Vector3Batch<T, N> {
+    fn from(vectors: [Vector3<T>; N]) -> Self {
+        Self::from_fn(|i| vectors[i])
+    }
+}
+
+impl<T: Scalar, const N: usize> From<Vector3Batch<T, N>> for [Vector3<T>; N] {
+    fn from(batch: Vector3Batch<T, N>) -> Self {
+        batch.to_array()
+    }
+}
+
+impl<T: Scalar, const N: usize> Add for Vector3Batch<T, N> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self {
+            x: array::from_fn(|i| self.x[i] + other.x[i]),
+            y: array::from_fn(|i| self.y[i] + other.y[i]),
+            z: array::from_fn(|i| self.z[i] + other.z[i]),
+        }
+    }
+}
+
+impl<T: Scalar, const N: usize> Sub for Vector3Batch<T, N> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self {
+            x: array::from_fn(|i| self.x[i] - other.x[i]),
+            y: array::from_fn(|i| self.y[i] - other.y[i]),
+            z: array::from_fn(|i| self.z[i] - other.z[i]),
+        }
+    }
+}
+
+impl<T: Scalar, const N: usize> Mul<T> for Vector3Batch<T, N> {
+    type Output = Self;
+
+    fn mul(self, other: T) -> Self {
+        Self {
+            x: self.x.map(|c| c * other),
+            y: self.y.map(|c| c * other),
+            z: self.z.map(|c| c * other),
+        }
+    }
+}
+
+/// Scales each lane by its own factor.
+impl<T: Scalar, const N: usize> Mul<[T; N]> for Vector3Batch<T, N> {
+    type Output = Self;
+
+    fn mul(self, other: [T; N]) -> Self {
+        Self {
+            x: array::from_fn(|i| self.x[i] * other[i]),
+            y: array::from_fn(|i| self.y[i] * other[i]),
+            z: array::from_fn(|i| self.z[i] * other[i]),
+        }
+    }
+}
+
+impl<T: Scalar, const N: usize> Neg for Vector3Batch<T, N> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self {
+            x: self.x.map(Neg::neg),
+            y: self.y.map(Neg::neg),
+            z: self.z.map(Neg::neg),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{Rng, same_f64};
+
+    fn random_vectors<const N: usize>(rng: &mut Rng) -> [Vector3; N] {
+        array::from_fn(|_| Vector3::new(rng.wide_f64(), rng.wide_f64(), rng.wide_f64()))
+    }
+
+    fn same_vector(a: Vector3, b: Vector3) -> bool {
+        same_f64(a.x, b.x) && same_f64(a.y, b.y) && same_f64(a.z, b.z)
+    }
+
+    #[test]
+    fn test_batch_matches_scalar() {
+        let mut rng = Rng::new(7);
+        for _ in 0..10_000 {
+            let a: [Vector3; 8] = random_vectors(&mut rng);
+            let b: [Vector3; 8] = random_vectors(&mut rng);
+            let (wa, wb) = (Vector3x8::from(a), Vector3x8::from(b));
+            let (dot, length) = (wa.dot(wb), wa.length());
+            let (cross, normalized, sum) = (wa.cross(wb), wa.normalized(), wa + wb);
+            for i in 0..8 {
+                assert!(same_f64(dot[i], a[i].dot(b[i])));
+                assert!(same_f64(length[i], a[i].length()));
+                assert!(same_vector(cross.lane(i), a[i].cross(b[i])));
+                assert!(same_vector(normalized.lane(i), a[i].normalized()));
+                assert!(same_vector(sum.lane(i), a[i] + b[i]));
+                assert!(same_vector((wa - wb).lane(i), a[i] - b[i]));
+                assert!(same_vector((wa * 3.0).lane(i), a[i] * 3.0));
+                assert!(same_vector((-wa).lane(i), -a[i]));
+            }
+        }
+    }
+
+    #[test]
+    fn test_batch_slice_round_trip() {
+        let vs: Vec<Vector3<f32>> = (0..12)
+            .map(|i| Vector3::new(i as f32, 2.0 * i as f32, -(i as f32)))
+            .collect();
+        let mut out = vec![Vector3::ZERO; 12];
+        for (chunk, out) in vs.chunks_exact(4).zip(out.chunks_exact_mut(4)) {
+            let batch = Vector3x4::from_slice(chunk);
+            assert_eq!(batch.x, [chunk[0].x, chunk[1].x, chunk[2].x, chunk[3].x]);
+            batch.write_to_slice(out);
+        }
+        assert_eq!(out, vs);
+
+        let batch = Vector3x4::from_slice(&vs[..4]);
+        assert_eq!(<[Vector3<f32>; 4]>::from(batch), vs[..4]);
+    }
+
+    #[test]
+    #[should_panic(expected = "needs exactly 4 vectors")]
+    fn test_batch_from_short_slice() {
+        Vector3x4::from_slice(&[Vector3::new(1.0, 2.0, 3.0)]);
+    }
+
+    #[test]
+    fn test_batch_mask_select() {
+        let a = Vector3x4::from([
+            Vector3::new(1.0, 0.0, 0.0),
+            Vector3::ZERO,
+            Vector3::new(0.0, 3.0, 4.0),
+            Vector3::new(f64::NAN, 0.0, 0.0),
+        ]);
+        let length = a.length();
+        let usable = Mask::from_fn(|i| length[i] > 0.0) & a.is_finite();
+        assert_eq!(usable, Mask([true, false, true, false]));
+        assert!(usable.any() && !usable.all() && !(!usable).none());
+
+        let fallback = Vector3x4::splat(Vector3::new(0.0, 0.0, 1.0));
+        let n = Vector3x4::select(usable, a.normalized(), fallback);
+        assert_eq!(
+            n.to_array(),
+            [
+                Vector3::new(1.0, 0.0, 0.0),
+                Vector3::new(0.0, 0.0, 1.0),
+                Vector3::new(0.0, 0.6, 0.8),
+                Vector3::new(0.0, 0.0, 1.0),
+            ]
+        );
+        assert_eq!(n.lanes_eq(fallback), Mask([false, true, false, true]));
+    }
+
+    #[test]
+    fn test_batch_integer() {
+        let a = Vector3x4::from([Vector3::new(1, 2, 3); 4]);
+        let b = Vector3x4::splat(Vector3::new(4, 5, 6));
+        assert_eq!(a.dot(b), [32; 4]);
+        assert_eq!(a.cross(b).lane(2), Vector3::new(-3, 6, -3));
+        assert_eq!((a * [1, 2, 3, 4]).lane(3), Vector3::new(4, 8, 12));
+    }
+}
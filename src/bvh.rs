@@ -0,0 +1,535 @@
+use crate::{Aabb, Ray, RayHit, Sphere, Triangle, Vector3};
+
+/// Items that can be stored in a `Bvh`.
+pub trait Bounded {
+    fn aabb(&self) -> Aabb;
+}
+
+/// Items that support the `Bvh` ray queries.
+pub trait RayIntersect: Bounded {
+    fn intersect_ray(&self, ray: Ray) -> Option<RayHit>;
+}
+
+/// Items that support the `Bvh` nearest-point query.
+pub trait ClosestPoint: Bounded {
+    fn closest_point(&self, point: Vector3) -> Vector3;
+}
+
+impl Bounded for Aabb {
+    fn aabb(&self) -> Aabb {
+        *self
+    }
+}
+
+impl RayIntersect for Aabb {
+    fn intersect_ray(&self, ray: Ray) -> Option<RayHit> {
+        Aabb::intersect_ray(*self, ray)
+    }
+}
+
+impl ClosestPoint for Aabb {
+    fn closest_point(&self, point: Vector3) -> Vector3 {
+        Aabb::closest_point(*self, point)
+    }
+}
+
+impl Bounded for Sphere {
+    fn aabb(&self) -> Aabb {
+        Sphere::aabb(*self)
+    }
+}
+
+impl RayIntersect for Sphere {
+    fn intersect_ray(&self, ray: Ray) -> Option<RayHit> {
+        Sphere::intersect_ray(*self, ray)
+    }
+}
+
+impl ClosestPoint for Sphere {
+    fn closest_point(&self, point: Vector3) -> Vector3 {
+        Sphere::closest_point(*self, point)
+    }
+}
+
+impl Bounded for Triangle {
+    fn aabb(&self) -> Aabb {
+        Triangle::aabb(*self)
+    }
+}
+
+impl RayIntersect for Triangle {
+    fn intersect_ray(&self, ray: Ray) -> Option<RayHit> {
+        Triangle::intersect_ray(*self, ray)
+    }
+}
+
+impl ClosestPoint for Triangle {
+    fn closest_point(&self, point: Vector3) -> Vector3 {
+        Triangle::closest_point(*self, point)
+    }
+}
+
+/// Leaves are never split below this many items.
+const MAX_LEAF_SIZE: usize = 4;
+/// Candidate split planes per axis, as centroid bins.
+const BIN_COUNT: usize = 16;
+/// Cost of visiting a node, relative to testing one item.
+const TRAVERSAL_COST: f64 = 1.0;
+
+/// A bounding volume hierarchy over a collection of items, built with the
+/// surface area heuristic.
+///
+/// Queries identify items by their index in the vector passed to `new`.
+#[derive(Debug, Clone)]
+pub struct Bvh<T> {
+    items: Vec<T>,
+    nodes: Vec<Node>,
+    /// Item indices, ordered so that every leaf covers a contiguous range.
+    order: Vec<usize>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Node {
+    aabb: Aabb,
+    kind: NodeKind,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum NodeKind {
+    Leaf {
+        start: usize,
+        end: usize,
+    },
+    /// The left child immediately follows its parent.
+    Interior {
+        right: usize,
+    },
+}
+
+impl<T: Bounded> Bvh<T> {
+    pub fn new(items: Vec<T>) -> Self {
+        let bounds: Vec<Aabb> = items.iter().map(Bounded::aabb).collect();
+        let mut order: Vec<usize> = (0..items.len()).collect();
+        let mut nodes = Vec::new();
+        if !items.is_empty() {
+            build(&mut nodes, &mut order, 0, &bounds);
+        }
+        Self {
+            items,
+            nodes,
+            order,
+        }
+    }
+
+    pub fn items(&self) -> &[T] {
+        &self.items
+    }
+
+    /// Mutable access for moving items. Call `refit` afterwards.
+    pub fn items_mut(&mut self) -> &mut [T] {
+        &mut self.items
+    }
+
+    pub fn into_items(self) -> Vec<T> {
+        self.items
+    }
+
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// The bounds of all items, or `Aabb::EMPTY` if there are none.
+    pub fn aabb(&self) -> Aabb {
+        self.nodes.first().map_or(Aabb::EMPTY, |node| node.aabb)
+    }
+
+    /// Recomputes the node bounds after items have moved, keeping the tree
+    /// structure. This is much cheaper than rebuilding, but queries slow down
+    /// as the items drift from where the tree was built; rebuild with `new`
+    /// when that matters.
+    pub fn refit(&mut self) {
+        // Children always come after their parent.
+        for index in (0..self.nodes.len()).rev() {
+            self.nodes[index].aabb = match self.nodes[index].kind {
+                NodeKind::Leaf { start, end } => self.order[start..end]
+                    .iter()
+                    .fold(Aabb::EMPTY, |aabb, &i| aabb.union(self.items[i].aabb())),
+                NodeKind::Interior { right } => {
+                    self.nodes[index + 1].aabb.union(self.nodes[right].aabb)
+                }
+            };
+        }
+    }
+
+    /// Indices of the items whose bounds overlap `aabb`.
+    pub fn overlapping(&self, aabb: Aabb) -> Vec<usize> {
+        let mut found = Vec::new();
+        let mut stack = self.root();
+        while let Some(index) = stack.pop() {
+            let node = self.nodes[index];
+            if !node.aabb.overlaps(aabb) {
+                continue;
+            }
+            match node.kind {
+                NodeKind::Leaf { start, end } => found.extend(
+                    self.order[start..end]
+                        .iter()
+                        .filter(|&&i| self.items[i].aabb().overlaps(aabb)),
+                ),
+                NodeKind::Interior { right } => stack.extend([index + 1, right]),
+            }
+        }
+        found
+    }
+
+    fn root(&self) -> Vec<usize> {
+        if self.nodes.is_empty() {
+            Vec::new()
+        } else {
+            vec![0]
+        }
+    }
+}
+
+impl<T: RayIntersect> Bvh<T> {
+    /// The nearest item hit by the ray, with its index.
+    pub fn ray_first_hit(&self, ray: Ray) -> Option<(usize, RayHit)> {
+        let mut best: Option<(usize, RayHit)> = None;
+        let mut stack: Vec<(usize, f64)> = Vec::new();
+        if let Some((t_near, _)) = self.nodes.first().and_then(|n| n.aabb.ray_interval(ray)) {
+            stack.push((0, t_near));
+        }
+        while let Some((index, t_near)) = stack.pop() {
+            if best.is_some_and(|(_, hit)| hit.distance < t_near) {
+                continue;
+            }
+            match self.nodes[index].kind {
+                NodeKind::Leaf { start, end } => {
+                    for &i in &self.order[start..end] {
+                        if let Some(hit) = self.items[i].intersect_ray(ray)
+                            && best.is_none_or(|(_, best)| hit.distance < best.distance)
+                        {
+                            best = Some((i, hit));
+                        }
+                    }
+                }
+                NodeKind::Interior { right } => {
+                    let near = |child: usize| {
+                        self.nodes[child]
+                            .aabb
+                            .ray_interval(ray)
+                            .map(|(t, _)| (child, t))
+                    };
+                    // Push the farther child first so the nearer one is
+                    // searched first and tightens the bound.
+                    match (near(index + 1), near(right)) {
+                        (Some(a), Some(b)) if a.1 <= b.1 => stack.extend([b, a]),
+                        (Some(a), Some(b)) => stack.extend([a, b]),
+                        (a, b) => stack.extend(a.or(b)),
+                    }
+                }
+            }
+        }
+        best
+    }
+
+    /// Every item hit by the ray, ordered by distance.
+    pub fn ray_all_hits(&self, ray: Ray) -> Vec<(usize, RayHit)> {
+        let mut hits = Vec::new();
+        let mut stack = self.root();
+        while let Some(index) = stack.pop() {
+            let node = self.nodes[index];
+            if node.aabb.ray_interval(ray).is_none() {
+                continue;
+            }
+            match node.kind {
+                NodeKind::Leaf { start, end } => hits.extend(
+                    self.order[start..end]
+                        .iter()
+                        .filter_map(|&i| Some((i, self.items[i].intersect_ray(ray)?))),
+                ),
+                NodeKind::Interior { right } => stack.extend([index + 1, right]),
+            }
+        }
+        hits.sort_by(|a, b| a.1.distance.total_cmp(&b.1.distance));
+        hits
+    }
+}
+
+impl<T: ClosestPoint> Bvh<T> {
+    /// The item closest to `point`, with its index and the closest point on it.
+    pub fn nearest(&self, point: Vector3) -> Option<(usize, Vector3)> {
+        let squared_distance = |p: Vector3| (p - point).dot(p - point);
+        let mut best: Option<(usize, Vector3, f64)> = None;
+        let mut stack: Vec<(usize, f64)> = self.root().into_iter().map(|i| (i, 0.0)).collect();
+        while let Some((index, bound)) = stack.pop() {
+            if best.is_some_and(|(_, _, d)| d < bound) {
+                continue;
+            }
+            match self.nodes[index].kind {
+                NodeKind::Leaf { start, end } => {
+                    for &i in &self.order[start..end] {
+                        let closest = self.items[i].closest_point(point);
+                        let d = squared_distance(closest);
+                        if best.is_none_or(|(_, _, best)| d < best) {
+                            best = Some((i, closest, d));
+                        }
+                    }
+                }
+                NodeKind::Interior { right } => {
+                    let bound = |child: usize| {
+                        (
+                            child,
+                            squared_distance(self.nodes[child].aabb.closest_point(point)),
+                        )
+                    };
+                    let (a, b) = (bound(index + 1), bound(right));
+                    stack.extend(if a.1 <= b.1 { [b, a] } else { [a, b] });
+                }
+            }
+        }
+        best.map(|(i, closest, _)| (i, closest))
+    }
+}
+
+/// Appends the subtree over `order` (which starts at `offset` in the full
+/// ordering) and returns the index of its root.
+fn build(nodes: &mut Vec<Node>, order: &mut [usize], offset: usize, bounds: &[Aabb]) -> usize {
+    let index = nodes.len();
+    let aabb = order
+        .iter()
+        .fold(Aabb::EMPTY, |aabb, &i| aabb.union(bounds[i]));
+    nodes.push(Node {
+        aabb,
+        kind: NodeKind::Leaf {
+            start: offset,
+            end: offset + order.len(),
+        },
+    });
+    if order.len() <= MAX_LEAF_SIZE {
+        return index;
+    }
+    let Some(mid) = partition(order, aabb, bounds) else {
+        return index;
+    };
+    let (left, right) = order.split_at_mut(mid);
+    build(nodes, left, offset, bounds);
+    let right = build(nodes, right, offset + mid, bounds);
+    nodes[index].kind = NodeKind::Interior { right };
+    index
+}
+
+/// Splits `order` at the binned centroid plane with the lowest surface area
+/// heuristic cost and returns the size of the left half, or `None` if a leaf
+/// is cheaper.
+fn partition(order: &mut [usize], aabb: Aabb, bounds: &[Aabb]) -> Option<usize> {
+    let centroids = Aabb::from_points(order.iter().map(|&i| bounds[i].center()));
+    let extents = centroids.extents();
+    let bin = |axis: usize, i: usize| {
+        let t = (bounds[i].center()[axis] - centroids.min[axis]) / extents[axis];
+        ((t * BIN_COUNT as f64) as usize).min(BIN_COUNT - 1)
+    };
+
+    // (cost, axis, last bin on the left)
+    let mut best: Option<(f64, usize, usize)> = None;
+    for axis in (0..3).filter(|&axis| extents[axis] > 0.0) {
+        let mut bins = [(Aabb::EMPTY, 0usize); BIN_COUNT];
+        for &i in order.iter() {
+            let b = &mut bins[bin(axis, i)];
+            *b = (b.0.union(bounds[i]), b.1 + 1);
+        }
+        // Right-hand sweep first, so the left-hand sweep can price each split.
+        let mut right_costs = [0.0; BIN_COUNT];
+        let (mut right, mut right_count) = (Aabb::EMPTY, 0);
+        for split in (1..BIN_COUNT).rev() {
+            right = right.union(bins[split].0);
+            right_count += bins[split].1;
+            right_costs[split - 1] = right.surface_area() * right_count as f64;
+        }
+        let (mut left, mut left_count) = (Aabb::EMPTY, 0);
+        for split in 0..BIN_COUNT - 1 {
+            left = left.union(bins[split].0);
+            left_count += bins[split].1;
+            if left_count == 0 || left_count == order.len() {
+                continue;
+            }
+            let cost = left.surface_area() * left_count as f64 + right_costs[split];
+            if best.is_none_or(|(best, _, _)| cost < best) {
+                best = Some((cost, axis, split));
+            }
+        }
+    }
+
+    // Both costs are scaled by the parent's surface area.
+    let (cost, axis, split) = best?;
+    let area = aabb.surface_area();
+    if TRAVERSAL_COST * area + cost >= order.len() as f64 * area {
+        return None;
+    }
+    let mut mid = 0;
+    for j in 0..order.len() {
+        if bin(axis, order[j]) <= split {
+            order.swap(j, mid);
+            mid += 1;
+        }
+    }
+    Some(mid)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::UnitVector3;
+    use crate::test_util::Rng;
+
+    fn triangle_soup(rng: &mut Rng, count: usize) -> Vec<Triangle> {
+        (0..count)
+            .map(|_| {
+                let center = rng.vector(-10.0, 10.0);
+                Triangle::new(
+                    center + rng.vector(-1.0, 1.0),
+                    center + rng.vector(-1.0, 1.0),
+                    center + rng.vector(-1.0, 1.0),
+                )
+            })
+            .collect()
+    }
+
+    fn random_ray(rng: &mut Rng) -> Ray {
+        loop {
+            if let Ok(direction) = UnitVector3::try_new(rng.vector(-1.0, 1.0)) {
+                return Ray::new(rng.vector(-15.0, 15.0), direction);
+            }
+        }
+    }
+
+    /// Checks that the node bounds enclose their contents and that every
+    /// item is in exactly one leaf.
+    fn check_structure<T: Bounded>(bvh: &Bvh<T>) {
+        let mut seen = vec![false; bvh.len()];
+        for (index, node) in bvh.nodes.iter().enumerate() {
+            match node.kind {
+                NodeKind::Leaf { start, end } => {
+                    for &i in &bvh.order[start..end] {
+                        assert_eq!(node.aabb.union(bvh.items[i].aabb()), node.aabb);
+                        assert!(!seen[i]);
+                        seen[i] = true;
+                    }
+                }
+                NodeKind::Interior { right } => {
+                    assert!(right > index + 1);
+                    let children = bvh.nodes[index + 1].aabb.union(bvh.nodes[right].aabb);
+                    assert_eq!(children, node.aabb);
+                }
+            }
+        }
+        assert!(seen.into_iter().all(|s| s));
+    }
+
+    fn check_against_brute_force(bvh: &Bvh<Triangle>, rng: &mut Rng) {
+        let items = bvh.items();
+        for _ in 0..200 {
+            let ray = random_ray(rng);
+            let mut expected: Vec<(usize, RayHit)> = items
+                .iter()
+                .enumerate()
+                .filter_map(|(i, t)| Some((i, t.intersect_ray(ray)?)))
+                .collect();
+            expected.sort_by(|a, b| a.1.distance.total_cmp(&b.1.distance));
+            assert_eq!(bvh.ray_first_hit(ray), expected.first().copied());
+            assert_eq!(bvh.ray_all_hits(ray), expected);
+
+            let query = Aabb::new(rng.vector(-12.0, 12.0), rng.vector(-12.0, 12.0));
+            let mut found = bvh.overlapping(query);
+            found.sort_unstable();
+            let expected: Vec<usize> = (0..items.len())
+                .filter(|&i| items[i].aabb().overlaps(query))
+                .collect();
+            assert_eq!(found, expected);
+
+            let point = rng.vector(-15.0, 15.0);
+            let (index, closest) = bvh.nearest(point).unwrap();
+            let expected = items
+                .iter()
+                .map(|t| t.distance(point))
+                .min_by(f64::total_cmp)
+                .unwrap();
+            assert_eq!(closest, items[index].closest_point(point));
+            assert_eq!((closest - point).length(), expected);
+        }
+    }
+
+    #[test]
+    fn test_bvh_matches_brute_force() {
+        let mut rng = Rng::new(15);
+        let bvh = Bvh::new(triangle_soup(&mut rng, 1000));
+        check_structure(&bvh);
+        assert!(bvh.nodes.len() > 1000 / MAX_LEAF_SIZE);
+        check_against_brute_force(&bvh, &mut rng);
+    }
+
+    #[test]
+    fn test_bvh_refit() {
+        let mut rng = Rng::new(16);
+        let mut bvh = Bvh::new(triangle_soup(&mut rng, 500));
+        for t in bvh.items_mut() {
+            let offset = rng.vector(-3.0, 3.0);
+            *t = Triangle::new(t.a + offset, t.b + offset, t.c + offset);
+        }
+        bvh.refit();
+        check_structure(&bvh);
+        check_against_brute_force(&bvh, &mut rng);
+    }
+
+    #[test]
+    fn test_bvh_spheres() {
+        let spheres: Vec<Sphere> = (0..10)
+            .map(|i| Sphere::new(Vector3::new(3.0 * i as f64, 0.0, 0.0), 1.0))
+            .collect();
+        let bvh = Bvh::new(spheres);
+        check_structure(&bvh);
+
+        let ray = Ray::new(Vector3::new(-5.0, 0.0, 0.0), UnitVector3::X);
+        let (index, hit) = bvh.ray_first_hit(ray).unwrap();
+        assert_eq!((index, hit.distance), (0, 4.0));
+        assert_eq!(bvh.ray_all_hits(ray).len(), 10);
+
+        // From inside a sphere, its exit is the first hit.
+        let ray = Ray::new(Vector3::new(15.0, 0.0, 0.0), UnitVector3::X);
+        let (index, hit) = bvh.ray_first_hit(ray).unwrap();
+        assert_eq!((index, hit.distance), (5, 1.0));
+
+        let (index, closest) = bvh.nearest(Vector3::new(9.0, 5.0, 0.0)).unwrap();
+        assert_eq!((index, closest), (3, Vector3::new(9.0, 1.0, 0.0)));
+    }
+
+    #[test]
+    fn test_bvh_degenerate_inputs() {
+        let empty: Bvh<Sphere> = Bvh::new(Vec::new());
+        assert!(empty.is_empty());
+        assert!(empty.aabb().is_empty());
+        let ray = Ray::new(Vector3::ZERO, UnitVector3::X);
+        assert_eq!(empty.ray_first_hit(ray), None);
+        assert!(empty.ray_all_hits(ray).is_empty());
+        assert!(
+            empty
+                .overlapping(Aabb::new(-Vector3::ONE, Vector3::ONE))
+                .is_empty()
+        );
+        assert_eq!(empty.nearest(Vector3::ZERO), None);
+
+        // Coincident items cannot be split and end up in a single leaf.
+        let bvh = Bvh::new(vec![Sphere::new(Vector3::ONE, 1.0); 20]);
+        check_structure(&bvh);
+        assert_eq!(bvh.nodes.len(), 1);
+        assert_eq!(
+            bvh.ray_all_hits(Ray::new(Vector3::new(1.0, -5.0, 1.0), UnitVector3::Y))
+                .len(),
+            20
+        );
+    }
+}
@@ -0,0 +1,81 @@
+//! `bytemuck` support, enabled by the `bytemuck` feature.
+//!
+//! All types here are `#[repr(C)]` with fields of a single scalar type, so
+//! they contain no padding and slices of them can be reinterpreted as slices
+//! of scalars or bytes. `UnitVector3` is deliberately excluded, since arbitrary
+//! bytes would not uphold its unit-length invariant.
+
+use ::bytemuck::{Pod, Zeroable};
+
+use crate::{Matrix3, Matrix4, Point3, Quaternion, Vector2, Vector3, Vector4};
+
+// SAFETY: each type is `#[repr(C)]` and consists solely of `T` fields (or
+// arrays of such types), so it has no padding, and every bit pattern valid
+// for `T` is valid for the whole.
+unsafe impl<T: Zeroable> Zeroable for Vector2<T> {}
+unsafe impl<T: Pod> Pod for Vector2<T> {}
+unsafe impl<T: Zeroable> Zeroable for Vector3<T> {}
+unsafe impl<T: Pod> Pod for Vector3<T> {}
+unsafe impl<T: Zeroable> Zeroable for Vector4<T> {}
+unsafe impl<T: Pod> Pod for Vector4<T> {}
+unsafe impl<T: Zeroable> Zeroable for Point3<T> {}
+unsafe impl<T: Pod> Pod for Point3<T> {}
+unsafe impl<T: Zeroable> Zeroable for Matrix3<T> {}
+unsafe impl<T: Pod> Pod for Matrix3<T> {}
+unsafe impl<T: Zeroable> Zeroable for Matrix4<T> {}
+unsafe impl<T: Pod> Pod for Matrix4<T> {}
+unsafe impl Zeroable for Quaternion {}
+unsafe impl Pod for Quaternion {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ::bytemuck::PodCastError;
+
+    #[test]
+    fn test_cast_vector_slice() {
+        let vs = [Vector3::new(1.0, 2.0, 3.0), Vector3::new(4.0, 5.0, 6.0)];
+        let flat: &[f64] = ::bytemuck::cast_slice(&vs);
+        assert_eq!(flat, [1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        let back: &[Vector3] = ::bytemuck::cast_slice(flat);
+        assert_eq!(back, vs);
+
+        let bytes: &[u8] = ::bytemuck::cast_slice(&vs);
+        assert_eq!(bytes.len(), 48);
+        assert_eq!(&bytes[8..16], &2.0f64.to_ne_bytes());
+    }
+
+    #[test]
+    fn test_cast_vector_slice_checked() {
+        let flat = [1.0f64, 2.0, 3.0, 4.0];
+        assert_eq!(
+            ::bytemuck::try_cast_slice::<f64, Vector3>(&flat),
+            Err(PodCastError::OutputSliceWouldHaveSlop)
+        );
+        assert_eq!(
+            ::bytemuck::try_cast_slice::<f64, Vector3>(&flat[..3]),
+            Ok(&[Vector3::new(1.0, 2.0, 3.0)][..])
+        );
+
+        // Reading f64 vectors from bytes at an odd offset must be rejected.
+        let bytes: &[u8] = ::bytemuck::cast_slice(&[0.0f64; 4]);
+        assert_eq!(
+            ::bytemuck::try_cast_slice::<u8, Vector3>(&bytes[1..25]),
+            Err(PodCastError::TargetAlignmentGreaterAndInputNotAligned)
+        );
+    }
+
+    #[test]
+    fn test_cast_other_types() {
+        let m = Matrix4::<f32>::IDENTITY;
+        let flat: &[f32; 16] = ::bytemuck::cast_ref(&m);
+        assert_eq!(flat[0], 1.0);
+        assert_eq!(flat[1], 0.0);
+        assert_eq!(flat[5], 1.0);
+        assert_eq!(flat[15], 1.0);
+
+        let q: [f64; 4] = ::bytemuck::cast(Quaternion::IDENTITY);
+        assert_eq!(q, [0.0, 0.0, 0.0, 1.0]);
+        assert_eq!(<Vector2<i32> as Zeroable>::zeroed(), Vector2::ZERO);
+    }
+}
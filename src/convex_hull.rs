@@ -0,0 +1,423 @@
+use std::collections::HashMap;
+
+use crate::predicates::{orient3d, triangle_normal};
+use crate::{GeometryError, UnitVector3, Vector3};
+
+/// The convex hull of a point set, as a closed triangle mesh.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConvexHull {
+    vertices: Vec<Vector3>,
+    vertex_indices: Vec<usize>,
+    faces: Vec<HullFace>,
+}
+
+/// A triangle of a `ConvexHull`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HullFace {
+    /// Indices into `ConvexHull::vertices`, counter-clockwise seen from
+    /// outside.
+    pub vertices: [usize; 3],
+    /// The outward normal.
+    pub normal: UnitVector3,
+    /// `neighbors[i]` is the index of the face sharing the edge from
+    /// `vertices[i]` to `vertices[(i + 1) % 3]`.
+    pub neighbors: [usize; 3],
+}
+
+impl ConvexHull {
+    /// Computes the hull with quickhull.
+    ///
+    /// Which side of a face a point lies on is decided with exact predicates,
+    /// so nearly coplanar input still gives a convex hull, and points lying
+    /// exactly on the hull surface do not become vertices. Returns
+    /// `TooFewPoints` for fewer than four points, `CollinearPoints` or
+    /// `CoplanarPoints` if the hull would have no volume, up to a small
+    /// tolerance scaled to the extent of the input, and `NonFiniteComponent`
+    /// for NaN or infinite input.
+    pub fn new(points: &[Vector3]) -> Result<Self, GeometryError> {
+        if points.iter().any(|p| !p.is_finite()) {
+            return Err(GeometryError::NonFiniteComponent);
+        }
+        if points.len() < 4 {
+            return Err(GeometryError::TooFewPoints);
+        }
+        Quickhull::new(points)?.run().finish()
+    }
+
+    pub fn vertices(&self) -> &[Vector3] {
+        &self.vertices
+    }
+
+    /// For each hull vertex, its index in the input slice.
+    pub fn vertex_indices(&self) -> &[usize] {
+        &self.vertex_indices
+    }
+
+    pub fn faces(&self) -> &[HullFace] {
+        &self.faces
+    }
+
+    pub fn volume(&self) -> f64 {
+        // Sum of tetrahedra from the first vertex, which is on the hull.
+        let origin = self.vertices[0];
+        self.faces
+            .iter()
+            .map(|face| {
+                let [a, b, c] = face.vertices.map(|v| self.vertices[v] - origin);
+                a.dot(b.cross(c))
+            })
+            .sum::<f64>()
+            / 6.0
+    }
+}
+
+struct Face {
+    vertices: [usize; 3],
+    normal: Vector3,
+    offset: f64,
+    neighbors: [usize; 3],
+    /// Points above this face not yet on the hull.
+    outside: Vec<usize>,
+    alive: bool,
+}
+
+struct Quickhull<'a> {
+    points: &'a [Vector3],
+    faces: Vec<Face>,
+}
+
+impl<'a> Quickhull<'a> {
+    /// Builds the initial tetrahedron from extreme points and assigns every
+    /// other point to a face it lies above.
+    fn new(points: &'a [Vector3]) -> Result<Self, GeometryError> {
+        let magnitude = points.iter().fold(Vector3::ZERO, |m, p| {
+            m.max(Vector3::new(p.x.abs(), p.y.abs(), p.z.abs()))
+        });
+        let tolerance = 3.0 * f64::EPSILON * (magnitude.x + magnitude.y + magnitude.z);
+
+        let farthest = |distance: &dyn Fn(Vector3) -> f64| {
+            (0..points.len())
+                .map(|i| (i, distance(points[i])))
+                .max_by(|a, b| a.1.total_cmp(&b.1))
+                .unwrap()
+        };
+
+        // The most distant pair among the extremes along each axis.
+        let mut extremes = Vec::with_capacity(6);
+        for axis in 0..3 {
+            extremes.push(farthest(&|p| -p[axis]).0);
+            extremes.push(farthest(&|p| p[axis]).0);
+        }
+        let (mut v0, mut v1, mut span) = (0, 0, 0.0);
+        for &i in &extremes {
+            for &j in &extremes {
+                let d = (points[i] - points[j]).length();
+                if d > span {
+                    (v0, v1, span) = (i, j, d);
+                }
+            }
+        }
+        if span <= tolerance {
+            return Err(GeometryError::CollinearPoints);
+        }
+
+        let direction = (points[v1] - points[v0]) / span;
+        let (v2, d) = farthest(&|p| (p - points[v0]).cross(direction).length());
+        if d <= tolerance {
+            return Err(GeometryError::CollinearPoints);
+        }
+
+        let normal = (points[v1] - points[v0])
+            .cross(points[v2] - points[v0])
+            .normalized();
+        let (v3, d) = farthest(&|p| normal.dot(p - points[v0]).abs());
+        if d <= tolerance {
+            return Err(GeometryError::CoplanarPoints);
+        }
+
+        let mut hull = Self {
+            points,
+            faces: Vec::new(),
+        };
+        // Orient the base so that v3 is behind it.
+        let (v1, v2) = if normal.dot(points[v3] - points[v0]) > 0.0 {
+            (v2, v1)
+        } else {
+            (v1, v2)
+        };
+        hull.push_face([v0, v1, v2], [1, 3, 2]);
+        hull.push_face([v1, v0, v3], [0, 2, 3]);
+        hull.push_face([v0, v2, v3], [0, 3, 1]);
+        hull.push_face([v2, v1, v3], [0, 1, 2]);
+
+        let candidates = (0..points.len()).filter(|&i| ![v0, v1, v2, v3].contains(&i));
+        hull.assign(candidates, &[0, 1, 2, 3]);
+        Ok(hull)
+    }
+
+    fn push_face(&mut self, vertices: [usize; 3], neighbors: [usize; 3]) -> usize {
+        let [a, b, c] = vertices.map(|v| self.points[v]);
+        let normal = triangle_normal(a, b, c).normalized();
+        self.faces.push(Face {
+            vertices,
+            normal,
+            offset: normal.dot(a),
+            neighbors,
+            outside: Vec::new(),
+            alive: true,
+        });
+        self.faces.len() - 1
+    }
+
+    fn distance(&self, face: usize, point: usize) -> f64 {
+        let face = &self.faces[face];
+        face.normal.dot(self.points[point]) - face.offset
+    }
+
+    /// Whether `point` is strictly above the plane of `face`, decided exactly.
+    fn sees(&self, face: usize, point: usize) -> bool {
+        let [a, b, c] = self.faces[face].vertices.map(|v| self.points[v]);
+        orient3d(a, b, c, self.points[point]) < 0.0
+    }
+
+    /// Gives each point to the first of `faces` it lies above; points above
+    /// none of them are inside the hull or on its surface and are dropped.
+    fn assign(&mut self, points: impl IntoIterator<Item = usize>, faces: &[usize]) {
+        for point in points {
+            if let Some(&face) = faces.iter().find(|&&f| self.sees(f, point)) {
+                self.faces[face].outside.push(point);
+            }
+        }
+    }
+
+    fn run(mut self) -> Self {
+        // Points are only ever assigned to new faces, so faces before the
+        // cursor never need another look.
+        let mut cursor = 0;
+        while let Some(face) = (cursor..self.faces.len())
+            .find(|&f| self.faces[f].alive && !self.faces[f].outside.is_empty())
+        {
+            cursor = face;
+            let eye = *self.faces[face]
+                .outside
+                .iter()
+                .max_by(|&&a, &&b| self.distance(face, a).total_cmp(&self.distance(face, b)))
+                .unwrap();
+            self.add_point(face, eye);
+        }
+        self
+    }
+
+    /// Replaces the faces visible from `eye` with a cone of faces joining
+    /// `eye` to the horizon.
+    ///
+    /// As visibility is exact, the hull so far is exactly convex and the
+    /// visible faces always form a disc with a simple horizon.
+    fn add_point(&mut self, start: usize, eye: usize) {
+        let mut visible = vec![start];
+        let mut horizon = Vec::new();
+        self.faces[start].alive = false;
+        let mut next = 0;
+        while next < visible.len() {
+            let face = visible[next];
+            next += 1;
+            for i in 0..3 {
+                let neighbor = self.faces[face].neighbors[i];
+                if !self.faces[neighbor].alive {
+                    continue;
+                }
+                if self.sees(neighbor, eye) {
+                    self.faces[neighbor].alive = false;
+                    visible.push(neighbor);
+                } else {
+                    let vertices = self.faces[face].vertices;
+                    horizon.push((vertices[i], vertices[(i + 1) % 3], neighbor));
+                }
+            }
+        }
+
+        let mut new_faces = Vec::with_capacity(horizon.len());
+        let (mut by_start, mut by_end) = (HashMap::new(), HashMap::new());
+        for &(a, b, neighbor) in &horizon {
+            let face = self.push_face([a, b, eye], [neighbor, usize::MAX, usize::MAX]);
+            let back = &mut self.faces[neighbor];
+            let edge = (0..3).find(|&j| back.vertices[j] == b).unwrap();
+            back.neighbors[edge] = face;
+            by_start.insert(a, face);
+            by_end.insert(b, face);
+            new_faces.push(face);
+        }
+        for &face in &new_faces {
+            let [a, b, _] = self.faces[face].vertices;
+            self.faces[face].neighbors[1] = by_start[&b];
+            self.faces[face].neighbors[2] = by_end[&a];
+        }
+
+        let orphans: Vec<usize> = visible
+            .iter()
+            .flat_map(|&f| std::mem::take(&mut self.faces[f].outside))
+            .filter(|&p| p != eye)
+            .collect();
+        self.assign(orphans, &new_faces);
+    }
+
+    /// Compacts the live faces and the vertices they use.
+    fn finish(self) -> Result<ConvexHull, GeometryError> {
+        let mut face_index = vec![usize::MAX; self.faces.len()];
+        let mut vertex_index = HashMap::new();
+        let mut vertex_indices = Vec::new();
+        let alive = self.faces.iter().enumerate().filter(|(_, f)| f.alive);
+        for (new, (old, face)) in alive.enumerate() {
+            face_index[old] = new;
+            for &v in &face.vertices {
+                vertex_index.entry(v).or_insert_with(|| {
+                    vertex_indices.push(v);
+                    vertex_indices.len() - 1
+                });
+            }
+        }
+        let faces = self
+            .faces
+            .iter()
+            .filter(|f| f.alive)
+            .map(|f| {
+                Ok(HullFace {
+                    vertices: f.vertices.map(|v| vertex_index[&v]),
+                    normal: UnitVector3::try_new(f.normal)?,
+                    neighbors: f.neighbors.map(|n| face_index[n]),
+                })
+            })
+            .collect::<Result<_, GeometryError>>()?;
+        Ok(ConvexHull {
+            vertices: vertex_indices.iter().map(|&i| self.points[i]).collect(),
+            vertex_indices,
+            faces,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::Rng;
+
+    /// Checks orientation, adjacency, Euler's formula and that no input point
+    /// lies outside the hull.
+    fn check_hull(hull: &ConvexHull, points: &[Vector3]) {
+        let faces = hull.faces();
+        let edges = faces.len() * 3 / 2;
+        assert_eq!(hull.vertices().len() + faces.len(), edges + 2);
+        for (f, face) in faces.iter().enumerate() {
+            for i in 0..3 {
+                let (a, b) = (face.vertices[i], face.vertices[(i + 1) % 3]);
+                let other = &faces[face.neighbors[i]];
+                let j = (0..3).find(|&j| other.vertices[j] == b).unwrap();
+                assert_eq!(other.vertices[(j + 1) % 3], a);
+                assert_eq!(other.neighbors[j], f);
+            }
+            let a = hull.vertices()[face.vertices[0]];
+            for &p in points {
+                assert!(face.normal.dot(p - a) <= 1e-9, "{p:?} is outside");
+            }
+        }
+        for (&v, &i) in hull.vertices().iter().zip(hull.vertex_indices()) {
+            assert_eq!(v, points[i]);
+        }
+    }
+
+    fn cube_lattice(n: usize) -> Vec<Vector3> {
+        let mut points = Vec::new();
+        for x in 0..n {
+            for y in 0..n {
+                for z in 0..n {
+                    points.push(Vector3::new(x as f64, y as f64, z as f64));
+                }
+            }
+        }
+        points
+    }
+
+    #[test]
+    fn test_convex_hull_tetrahedron() {
+        let points = [
+            Vector3::ZERO,
+            Vector3::new(1.0, 0.0, 0.0),
+            Vector3::new(0.0, 1.0, 0.0),
+            Vector3::new(0.0, 0.0, 1.0),
+        ];
+        let hull = ConvexHull::new(&points).unwrap();
+        check_hull(&hull, &points);
+        assert_eq!(hull.faces().len(), 4);
+        assert_vec_approx_eq!(hull.volume(), 1.0 / 6.0);
+        let normals: Vec<Vector3> = hull.faces().iter().map(|f| *f.normal).collect();
+        assert!(normals.contains(&Vector3::new(0.0, 0.0, -1.0)));
+    }
+
+    #[test]
+    fn test_convex_hull_lattice_drops_coplanar_points() {
+        let points = cube_lattice(5);
+        let hull = ConvexHull::new(&points).unwrap();
+        check_hull(&hull, &points);
+        assert_eq!(hull.vertices().len(), 8);
+        assert_eq!(hull.faces().len(), 12);
+        assert_vec_approx_eq!(hull.volume(), 64.0);
+    }
+
+    #[test]
+    fn test_convex_hull_noisy_lattice() {
+        // Random subsets of a lattice, nudged off it by a few ulps and up,
+        // have many nearly coplanar faces and points nearly on hull edges.
+        let lattice = cube_lattice(3);
+        let mut rng = Rng::new(23);
+        for jitter in [1e-15, 1e-14, 1e-12, 1e-10, 1e-8] {
+            for _ in 0..200 {
+                let mut points = Vec::new();
+                for &p in &lattice {
+                    if rng.unit() < 0.6 {
+                        points.push(p + rng.vector(-jitter, jitter));
+                    }
+                }
+                if let Ok(hull) = ConvexHull::new(&points) {
+                    check_hull(&hull, &points);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_convex_hull_random() {
+        let mut rng = Rng::new(17);
+        for count in [4, 10, 100, 2000] {
+            let points: Vec<Vector3> = (0..count).map(|_| rng.vector(-1.0, 1.0)).collect();
+            check_hull(&ConvexHull::new(&points).unwrap(), &points);
+        }
+
+        // Points on a sphere are all hull vertices.
+        let points: Vec<Vector3> = (0..500)
+            .filter_map(|_| rng.vector(-1.0, 1.0).try_normalized().ok())
+            .collect();
+        let hull = ConvexHull::new(&points).unwrap();
+        check_hull(&hull, &points);
+        assert_eq!(hull.vertices().len(), points.len());
+    }
+
+    #[test]
+    fn test_convex_hull_degenerate_input() {
+        let line: Vec<Vector3> = (0..10).map(|i| Vector3::ONE * i as f64).collect();
+        assert_eq!(ConvexHull::new(&line), Err(GeometryError::CollinearPoints));
+        let same = vec![Vector3::ONE; 10];
+        assert_eq!(ConvexHull::new(&same), Err(GeometryError::CollinearPoints));
+        let square: Vec<Vector3> = cube_lattice(3).into_iter().filter(|p| p.z == 1.0).collect();
+        assert_eq!(ConvexHull::new(&square), Err(GeometryError::CoplanarPoints));
+        assert_eq!(
+            ConvexHull::new(&line[..3]),
+            Err(GeometryError::TooFewPoints)
+        );
+        let mut points = cube_lattice(2);
+        points[3].y = f64::NAN;
+        assert_eq!(
+            ConvexHull::new(&points),
+            Err(GeometryError::NonFiniteComponent)
+        );
+    }
+}
@@ -0,0 +1,288 @@
+use std::marker::PhantomData;
+
+use crate::Vector3;
+
+/// How the two angles of a `Spherical` are named.
+///
+/// Both conventions measure the polar angle from the +z axis, in `[0, π]`,
+/// and the azimuth in the xy-plane from the +x axis towards +y, in
+/// `(-π, π]`. They differ only in which of `theta` and `phi` is which.
+pub trait SphericalConvention: Copy {
+    #[doc(hidden)]
+    fn to_polar_azimuth(theta: f64, phi: f64) -> (f64, f64);
+    #[doc(hidden)]
+    fn from_polar_azimuth(polar: f64, azimuth: f64) -> (f64, f64);
+}
+
+/// The physics (ISO 80000-2) convention: `theta` is the polar angle and
+/// `phi` the azimuth.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct PhysicsConvention;
+
+/// The mathematics convention: `theta` is the azimuth and `phi` the polar
+/// angle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct MathConvention;
+
+impl SphericalConvention for PhysicsConvention {
+    fn to_polar_azimuth(theta: f64, phi: f64) -> (f64, f64) {
+        (theta, phi)
+    }
+
+    fn from_polar_azimuth(polar: f64, azimuth: f64) -> (f64, f64) {
+        (polar, azimuth)
+    }
+}
+
+impl SphericalConvention for MathConvention {
+    fn to_polar_azimuth(theta: f64, phi: f64) -> (f64, f64) {
+        (phi, theta)
+    }
+
+    fn from_polar_azimuth(polar: f64, azimuth: f64) -> (f64, f64) {
+        (azimuth, polar)
+    }
+}
+
+/// Spherical coordinates: a radius and two angles in radians, whose meaning
+/// is fixed by the convention `C`.
+///
+/// Converting from a `Vector3` always gives `r >= 0`, a polar angle in
+/// `[0, π]` and an azimuth in `(-π, π]`. On the z axis the azimuth is
+/// undefined and set to zero; at the origin both angles are zero.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Spherical<C = PhysicsConvention> {
+    pub r: f64,
+    pub theta: f64,
+    pub phi: f64,
+    convention: PhantomData<C>,
+}
+
+/// Cylindrical coordinates: the distance `rho` from the z axis, the azimuth
+/// `phi` in radians and the height `z`.
+///
+/// Converting from a `Vector3` always gives `rho >= 0` and `phi` in
+/// `(-π, π]`, with `phi` zero on the z axis.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Cylindrical {
+    pub rho: f64,
+    pub phi: f64,
+    pub z: f64,
+}
+
+/// `atan2(y, x)` in `(-π, π]`: zero when both inputs are zero, and `π`
+/// rather than `-π` for `y = -0.0` with negative `x`.
+fn azimuth(x: f64, y: f64) -> f64 {
+    if x == 0.0 && y == 0.0 {
+        0.0
+    } else {
+        // Adding zero turns `-0.0` into `+0.0`.
+        (y + 0.0).atan2(x)
+    }
+}
+
+impl<C: SphericalConvention> Spherical<C> {
+    pub fn new(r: f64, theta: f64, phi: f64) -> Self {
+        Self {
+            r,
+            theta,
+            phi,
+            convention: PhantomData,
+        }
+    }
+
+    pub fn from_polar_azimuth(r: f64, polar: f64, azimuth: f64) -> Self {
+        let (theta, phi) = C::from_polar_azimuth(polar, azimuth);
+        Self::new(r, theta, phi)
+    }
+
+    /// The angle from the +z axis.
+    pub fn polar(self) -> f64 {
+        C::to_polar_azimuth(self.theta, self.phi).0
+    }
+
+    /// The angle in the xy-plane from the +x axis towards +y.
+    pub fn azimuth(self) -> f64 {
+        C::to_polar_azimuth(self.theta, self.phi).1
+    }
+
+    pub fn from_vector(v: Vector3) -> Self {
+        let rho = v.x.hypot(v.y);
+        let r = rho.hypot(v.z);
+        if r == 0.0 {
+            return Self::new(0.0, 0.0, 0.0);
+        }
+        Self::from_polar_azimuth(r, rho.atan2(v.z), azimuth(v.x, v.y))
+    }
+
+    pub fn to_vector(self) -> Vector3 {
+        let (sin_polar, cos_polar) = self.polar().sin_cos();
+        let (sin_azimuth, cos_azimuth) = self.azimuth().sin_cos();
+        Vector3::new(
+            self.r * sin_polar * cos_azimuth,
+            self.r * sin_polar * sin_azimuth,
+            self.r * cos_polar,
+        )
+    }
+
+    /// The same coordinates under another naming convention.
+    pub fn to_convention<D: SphericalConvention>(self) -> Spherical<D> {
+        Spherical::from_polar_azimuth(self.r, self.polar(), self.azimuth())
+    }
+}
+
+impl Cylindrical {
+    pub fn new(rho: f64, phi: f64, z: f64) -> Self {
+        Self { rho, phi, z }
+    }
+
+    pub fn from_vector(v: Vector3) -> Self {
+        Self::new(v.x.hypot(v.y), azimuth(v.x, v.y), v.z)
+    }
+
+    pub fn to_vector(self) -> Vector3 {
+        let (sin, cos) = self.phi.sin_cos();
+        Vector3::new(self.rho * cos, self.rho * sin, self.z)
+    }
+}
+
+impl<C: SphericalConvention> From<Vector3> for Spherical<C> {
+    fn from(v: Vector3) -> Self {
+        Self::from_vector(v)
+    }
+}
+
+impl<C: SphericalConvention> From<Spherical<C>> for Vector3 {
+    fn from(s: Spherical<C>) -> Self {
+        s.to_vector()
+    }
+}
+
+impl From<Vector3> for Cylindrical {
+    fn from(v: Vector3) -> Self {
+        Self::from_vector(v)
+    }
+}
+
+impl From<Cylindrical> for Vector3 {
+    fn from(c: Cylindrical) -> Self {
+        c.to_vector()
+    }
+}
+
+impl<C: SphericalConvention> From<Spherical<C>> for Cylindrical {
+    fn from(s: Spherical<C>) -> Self {
+        let (sin, cos) = s.polar().sin_cos();
+        Self::new(s.r * sin, s.azimuth(), s.r * cos)
+    }
+}
+
+impl<C: SphericalConvention> From<Cylindrical> for Spherical<C> {
+    fn from(c: Cylindrical) -> Self {
+        let r = c.rho.hypot(c.z);
+        if r == 0.0 {
+            return Self::new(0.0, 0.0, 0.0);
+        }
+        let azimuth = if c.rho == 0.0 { 0.0 } else { c.phi };
+        Self::from_polar_azimuth(r, c.rho.atan2(c.z), azimuth)
+    }
+}
+
+impl From<Spherical<MathConvention>> for Spherical<PhysicsConvention> {
+    fn from(s: Spherical<MathConvention>) -> Self {
+        s.to_convention()
+    }
+}
+
+impl From<Spherical<PhysicsConvention>> for Spherical<MathConvention> {
+    fn from(s: Spherical<PhysicsConvention>) -> Self {
+        s.to_convention()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::Rng;
+    use std::f64::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+
+    type Physics = Spherical<PhysicsConvention>;
+    type Math = Spherical<MathConvention>;
+
+    #[test]
+    fn test_spherical_conventions() {
+        let v = Vector3::new(1.0, 1.0, 0.0);
+        let physics = Physics::from(v);
+        assert_vec_approx_eq!(physics.r, 2f64.sqrt());
+        assert_eq!((physics.theta, physics.phi), (FRAC_PI_2, FRAC_PI_4));
+
+        let math = Math::from(v);
+        assert_eq!((math.theta, math.phi), (FRAC_PI_4, FRAC_PI_2));
+        assert_eq!((math.polar(), math.azimuth()), (FRAC_PI_2, FRAC_PI_4));
+        assert_eq!(Physics::from(math), physics);
+        assert_eq!(physics.to_convention::<MathConvention>(), math);
+    }
+
+    #[test]
+    fn test_spherical_round_trip() {
+        let mut rng = Rng::new(19);
+        for _ in 0..1000 {
+            let v = rng.vector(-10.0, 10.0);
+            let s = Physics::from(v);
+            assert!(s.r >= 0.0);
+            assert!((0.0..=PI).contains(&s.theta));
+            assert!(s.phi > -PI && s.phi <= PI);
+            assert_vec_approx_eq!(Vector3::from(s), v, epsilon = 1e-12);
+            assert_vec_approx_eq!(Vector3::from(Math::from(v)), v, epsilon = 1e-12);
+
+            let c = Cylindrical::from(v);
+            assert_vec_approx_eq!(Vector3::from(c), v, epsilon = 1e-12);
+            let from_spherical = Cylindrical::from(s);
+            assert_vec_approx_eq!(from_spherical.rho, c.rho, epsilon = 1e-12);
+            assert_vec_approx_eq!(from_spherical.z, c.z, epsilon = 1e-12);
+            assert_eq!(from_spherical.phi, c.phi);
+            let back = Physics::from(c);
+            assert_vec_approx_eq!(back.theta, s.theta, epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_spherical_poles_and_origin() {
+        let north = Physics::from(Vector3::new(0.0, 0.0, 5.0));
+        assert_eq!((north.r, north.theta, north.phi), (5.0, 0.0, 0.0));
+        let south = Physics::from(Vector3::new(-0.0, -0.0, -5.0));
+        assert_eq!((south.r, south.theta, south.phi), (5.0, PI, 0.0));
+        let origin = Physics::from(Vector3::new(-0.0, 0.0, -0.0));
+        assert_eq!((origin.r, origin.theta, origin.phi), (0.0, 0.0, 0.0));
+        assert_eq!(Vector3::from(origin), Vector3::ZERO);
+
+        // Any azimuth at a pole maps to the same point.
+        let pole = Physics::new(2.0, 0.0, 1.234);
+        assert_vec_approx_eq!(Vector3::from(pole), Vector3::new(0.0, 0.0, 2.0));
+
+        let negative_x = Physics::from(Vector3::new(-1.0, 0.0, 0.0));
+        assert_eq!(negative_x.phi, PI);
+        // A negative zero y must not flip the azimuth to -π.
+        for z in [-2.0, 0.0, 3.0] {
+            let v = Vector3::new(-1.0, -0.0, z);
+            assert_eq!(Physics::from(v).phi, PI);
+            assert_eq!(Math::from(v).azimuth(), PI);
+            assert_eq!(Cylindrical::from(v).phi, PI);
+        }
+    }
+
+    #[test]
+    fn test_cylindrical_axis() {
+        let c = Cylindrical::from(Vector3::new(-0.0, -0.0, 3.0));
+        assert_eq!(c, Cylindrical::new(0.0, 0.0, 3.0));
+        let s = Physics::from(Cylindrical::new(0.0, 2.0, -3.0));
+        assert_eq!((s.r, s.theta, s.phi), (3.0, PI, 0.0));
+        let s = Physics::from(Cylindrical::new(0.0, 2.0, 0.0));
+        assert_eq!((s.r, s.theta, s.phi), (0.0, 0.0, 0.0));
+        assert_vec_approx_eq!(
+            Vector3::from(Cylindrical::new(2.0, FRAC_PI_2, 1.0)),
+            Vector3::new(0.0, 2.0, 1.0),
+            epsilon = 1e-15
+        );
+    }
+}
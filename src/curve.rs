@@ -0,0 +1,798 @@
+use crate::{GeometryError, Vector3};
+
+// Below this sine of the angle between the first and second derivatives,
+// `Curve::frenet_frame` treats the curve as straight.
+const STRAIGHT_TOLERANCE: f64 = 1e-10;
+
+/// A curve parameterized by `t` in `[0, 1]`.
+///
+/// The provided methods build on the position and its derivatives with
+/// respect to `t`.
+pub trait Curve {
+    fn position(&self, t: f64) -> Vector3;
+    fn derivative(&self, t: f64) -> Vector3;
+    fn second_derivative(&self, t: f64) -> Vector3;
+
+    /// The Frenet-Serret frame at `t`.
+    ///
+    /// Fails with `DegenerateVector` where the derivative vanishes or the
+    /// curve is locally straight, as the normal is undefined there. The
+    /// curve counts as straight when the sine of the angle between the first
+    /// and second derivatives is below `1e-10`, so that rounding noise on a
+    /// straight curve doesn't pick an arbitrary normal.
+    fn frenet_frame(&self, t: f64) -> Result<CurveFrame, GeometryError> {
+        let d1 = self.derivative(t);
+        let d2 = self.second_derivative(t);
+        let tangent = d1.try_normalized()?;
+        let cross = d1.cross(d2);
+        if cross.length() <= STRAIGHT_TOLERANCE * d1.length() * d2.length() {
+            return Err(GeometryError::DegenerateVector);
+        }
+        let binormal = cross.try_normalized()?;
+        Ok(CurveFrame {
+            tangent,
+            normal: binormal.cross(tangent),
+            binormal,
+        })
+    }
+
+    /// Rotation-minimizing frames at each of `parameters`, computed by
+    /// parallel transport with the double reflection method of Wang et al.
+    ///
+    /// Unlike Frenet frames these are defined along straight stretches and
+    /// don't flip at inflections, so they suit orienting objects along a
+    /// path. The first frame is the Frenet frame if it exists. Parameters
+    /// should be ordered and closely spaced, as the transport is
+    /// approximated between consecutive samples.
+    fn rotation_minimizing_frames(&self, parameters: &[f64]) -> Vec<CurveFrame> {
+        let mut frames = Vec::with_capacity(parameters.len());
+        let Some(&first) = parameters.first() else {
+            return frames;
+        };
+        let mut frame = self.frenet_frame(first).unwrap_or_else(|_| {
+            let tangent = self
+                .derivative(first)
+                .try_normalized()
+                .unwrap_or(Vector3::new(1.0, 0.0, 0.0));
+            CurveFrame::from_tangent_normal(tangent, tangent.any_perpendicular())
+        });
+        let mut position = self.position(first);
+        frames.push(frame);
+        for &t in &parameters[1..] {
+            let next_position = self.position(t);
+            // Where the derivative vanishes, keep the previous tangent.
+            let next_tangent = self.derivative(t).normalized_or(frame.tangent);
+            let v1 = next_position - position;
+            let c1 = v1.dot(v1);
+            let (mut normal, mut tangent) = (frame.normal, frame.tangent);
+            if c1 > 0.0 {
+                normal -= v1 * (2.0 / c1 * v1.dot(normal));
+                tangent -= v1 * (2.0 / c1 * v1.dot(tangent));
+            }
+            let v2 = next_tangent - tangent;
+            let c2 = v2.dot(v2);
+            if c2 > 0.0 {
+                normal -= v2 * (2.0 / c2 * v2.dot(normal));
+            }
+            frame = CurveFrame::from_tangent_normal(next_tangent, normal);
+            position = next_position;
+            frames.push(frame);
+        }
+        frames
+    }
+}
+
+/// An orthonormal, right-handed frame along a curve: `binormal` is
+/// `tangent × normal`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CurveFrame {
+    pub tangent: Vector3,
+    pub normal: Vector3,
+    pub binormal: Vector3,
+}
+
+/// A table of arc length against parameter for a `Curve`, for moving along
+/// it at constant speed.
+///
+/// Arc length is integrated with five-point Gauss-Legendre quadrature on
+/// each of a number of equal parameter segments; lookups interpolate
+/// linearly within a segment, so their error shrinks quadratically with the
+/// segment count.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ArcLengthTable {
+    /// Cumulative arc length at `t = i / segments`.
+    lengths: Vec<f64>,
+}
+
+/// A quadratic Bézier curve through `p0` and `p2`, pulled towards `p1`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct QuadraticBezier {
+    pub p0: Vector3,
+    pub p1: Vector3,
+    pub p2: Vector3,
+}
+
+/// A cubic Bézier curve through `p0` and `p3` with control points `p1` and
+/// `p2`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CubicBezier {
+    pub p0: Vector3,
+    pub p1: Vector3,
+    pub p2: Vector3,
+    pub p3: Vector3,
+}
+
+/// A cubic Hermite curve from `p0` with tangent `m0` to `p1` with tangent
+/// `m1`. The tangents are derivatives with respect to `t`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CubicHermite {
+    pub p0: Vector3,
+    pub m0: Vector3,
+    pub p1: Vector3,
+    pub m1: Vector3,
+}
+
+/// How a `CatmullRom` spaces its knots: consecutive knots are
+/// `|p[i+1] - p[i]|^alpha` apart.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CatmullRomParameterization {
+    /// `alpha = 0`: equally spaced knots, the classic Catmull-Rom spline.
+    Uniform,
+    /// `alpha = 0.5`: avoids cusps and self-intersections within a segment.
+    #[default]
+    Centripetal,
+    /// `alpha = 1`: knots spaced by chord length.
+    Chordal,
+}
+
+/// One segment of a Catmull-Rom spline, running from `points[1]` to
+/// `points[2]`; `points[0]` and `points[3]` only shape the tangents.
+///
+/// A spline through `n` points is the `n - 3` segments over each window of
+/// four consecutive points, and is C1 continuous across them.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CatmullRom {
+    pub points: [Vector3; 4],
+    pub parameterization: CatmullRomParameterization,
+}
+
+// All curves here are parameterized by `t` in `[0, 1]`, and the derivatives
+// are taken with respect to `t`. Values of `t` outside that range extrapolate
+// the polynomial.
+
+impl QuadraticBezier {
+    pub fn new(p0: Vector3, p1: Vector3, p2: Vector3) -> Self {
+        Self { p0, p1, p2 }
+    }
+
+    pub fn position(self, t: f64) -> Vector3 {
+        let s = 1.0 - t;
+        self.p0 * (s * s) + self.p1 * (2.0 * s * t) + self.p2 * (t * t)
+    }
+
+    pub fn derivative(self, t: f64) -> Vector3 {
+        ((self.p1 - self.p0) * (1.0 - t) + (self.p2 - self.p1) * t) * 2.0
+    }
+
+    pub fn second_derivative(self, _t: f64) -> Vector3 {
+        (self.p2 - self.p1 * 2.0 + self.p0) * 2.0
+    }
+}
+
+impl CubicBezier {
+    pub fn new(p0: Vector3, p1: Vector3, p2: Vector3, p3: Vector3) -> Self {
+        Self { p0, p1, p2, p3 }
+    }
+
+    pub fn position(self, t: f64) -> Vector3 {
+        let s = 1.0 - t;
+        self.p0 * (s * s * s)
+            + self.p1 * (3.0 * s * s * t)
+            + self.p2 * (3.0 * s * t * t)
+            + self.p3 * (t * t * t)
+    }
+
+    pub fn derivative(self, t: f64) -> Vector3 {
+        let s = 1.0 - t;
+        ((self.p1 - self.p0) * (s * s)
+            + (self.p2 - self.p1) * (2.0 * s * t)
+            + (self.p3 - self.p2) * (t * t))
+            * 3.0
+    }
+
+    pub fn second_derivative(self, t: f64) -> Vector3 {
+        let a = self.p2 - self.p1 * 2.0 + self.p0;
+        let b = self.p3 - self.p2 * 2.0 + self.p1;
+        (a * (1.0 - t) + b * t) * 6.0
+    }
+}
+
+impl CubicHermite {
+    pub fn new(p0: Vector3, m0: Vector3, p1: Vector3, m1: Vector3) -> Self {
+        Self { p0, m0, p1, m1 }
+    }
+
+    pub fn position(self, t: f64) -> Vector3 {
+        let t2 = t * t;
+        let t3 = t2 * t;
+        self.p0 * (2.0 * t3 - 3.0 * t2 + 1.0)
+            + self.m0 * (t3 - 2.0 * t2 + t)
+            + self.p1 * (3.0 * t2 - 2.0 * t3)
+            + self.m1 * (t3 - t2)
+    }
+
+    pub fn derivative(self, t: f64) -> Vector3 {
+        let t2 = t * t;
+        (self.p0 - self.p1) * (6.0 * t2 - 6.0 * t)
+            + self.m0 * (3.0 * t2 - 4.0 * t + 1.0)
+            + self.m1 * (3.0 * t2 - 2.0 * t)
+    }
+
+    pub fn second_derivative(self, t: f64) -> Vector3 {
+        (self.p0 - self.p1) * (12.0 * t - 6.0)
+            + self.m0 * (6.0 * t - 4.0)
+            + self.m1 * (6.0 * t - 2.0)
+    }
+}
+
+impl CatmullRomParameterization {
+    pub fn alpha(self) -> f64 {
+        match self {
+            Self::Uniform => 0.0,
+            Self::Centripetal => 0.5,
+            Self::Chordal => 1.0,
+        }
+    }
+}
+
+impl CatmullRom {
+    pub fn new(points: [Vector3; 4], parameterization: CatmullRomParameterization) -> Self {
+        Self {
+            points,
+            parameterization,
+        }
+    }
+
+    /// The segment as a Hermite curve over `t` in `[0, 1]`, with tangents
+    /// from the non-uniform Catmull-Rom knot sequence.
+    pub fn to_hermite(self) -> CubicHermite {
+        let [p0, p1, p2, p3] = self.points;
+        let alpha = self.parameterization.alpha();
+        // Coincident points would give a zero knot interval and divide by
+        // zero; give them a unit interval instead.
+        let interval = |a: Vector3, b: Vector3| {
+            let dt = (b - a).length().powf(alpha);
+            if dt > 0.0 { dt } else { 1.0 }
+        };
+        let (dt0, dt1, dt2) = (interval(p0, p1), interval(p1, p2), interval(p2, p3));
+        let m1 = (p1 - p0) / dt0 - (p2 - p0) / (dt0 + dt1) + (p2 - p1) / dt1;
+        let m2 = (p2 - p1) / dt1 - (p3 - p1) / (dt1 + dt2) + (p3 - p2) / dt2;
+        // Rescale from the knot interval `[t1, t2]` to `[0, 1]`.
+        CubicHermite::new(p1, m1 * dt1, p2, m2 * dt1)
+    }
+
+    pub fn position(self, t: f64) -> Vector3 {
+        self.to_hermite().position(t)
+    }
+
+    pub fn derivative(self, t: f64) -> Vector3 {
+        self.to_hermite().derivative(t)
+    }
+
+    pub fn second_derivative(self, t: f64) -> Vector3 {
+        self.to_hermite().second_derivative(t)
+    }
+}
+
+impl CurveFrame {
+    /// Completes a frame from a unit tangent and an approximate normal,
+    /// which is made orthogonal to the tangent.
+    fn from_tangent_normal(tangent: Vector3, normal: Vector3) -> Self {
+        let normal = (normal - tangent * tangent.dot(normal))
+            .try_normalized()
+            .unwrap_or_else(|_| tangent.any_perpendicular());
+        Self {
+            tangent,
+            normal,
+            binormal: tangent.cross(normal),
+        }
+    }
+}
+
+// Nodes and weights of five-point Gauss-Legendre quadrature on `[-1, 1]`.
+const GAUSS_LEGENDRE: [(f64, f64); 5] = [
+    (0.0, 0.568_888_888_888_888_9),
+    (-0.538_469_310_105_683_1, 0.478_628_670_499_366_5),
+    (0.538_469_310_105_683_1, 0.478_628_670_499_366_5),
+    (-0.906_179_845_938_664, 0.236_926_885_056_189_1),
+    (0.906_179_845_938_664, 0.236_926_885_056_189_1),
+];
+
+impl ArcLengthTable {
+    /// Panics if `segments` is zero.
+    pub fn new<C: Curve + ?Sized>(curve: &C, segments: usize) -> Self {
+        assert!(
+            segments > 0,
+            "ArcLengthTable::new needs at least one segment"
+        );
+        let h = 1.0 / segments as f64;
+        let mut lengths = Vec::with_capacity(segments + 1);
+        let mut total = 0.0;
+        lengths.push(total);
+        for i in 0..segments {
+            let mid = (i as f64 + 0.5) * h;
+            let segment: f64 = GAUSS_LEGENDRE
+                .iter()
+                .map(|&(x, w)| w * curve.derivative(mid + x * h / 2.0).length())
+                .sum();
+            total += segment * h / 2.0;
+            lengths.push(total);
+        }
+        Self { lengths }
+    }
+
+    pub fn segments(&self) -> usize {
+        self.lengths.len() - 1
+    }
+
+    /// The total length of the curve.
+    pub fn length(&self) -> f64 {
+        self.lengths[self.segments()]
+    }
+
+    /// The arc length from the start of the curve to `t`, with `t` clamped
+    /// to `[0, 1]`.
+    pub fn arc_length(&self, t: f64) -> f64 {
+        let x = t.clamp(0.0, 1.0) * self.segments() as f64;
+        let i = (x as usize).min(self.segments() - 1);
+        let (s0, s1) = (self.lengths[i], self.lengths[i + 1]);
+        s0 + (s1 - s0) * (x - i as f64)
+    }
+
+    /// The parameter at arc length `s` from the start, the inverse of
+    /// `arc_length`, with `s` clamped to `[0, length]`.
+    ///
+    /// Where the curve stalls, so that a range of parameters share one arc
+    /// length, the smallest is returned.
+    pub fn parameter_at(&self, s: f64) -> f64 {
+        let s = s.clamp(0.0, self.length());
+        // The first segment ending at or beyond `s`.
+        let i = self.lengths[1..].partition_point(|&length| length < s);
+        let (s0, s1) = (self.lengths[i], self.lengths[i + 1]);
+        let fraction = if s1 > s0 { (s - s0) / (s1 - s0) } else { 0.0 };
+        (i as f64 + fraction) / self.segments() as f64
+    }
+}
+
+impl From<QuadraticBezier> for CubicBezier {
+    /// Degree elevation; the curve is unchanged.
+    fn from(q: QuadraticBezier) -> Self {
+        Self::new(
+            q.p0,
+            q.p0 + (q.p1 - q.p0) * (2.0 / 3.0),
+            q.p2 + (q.p1 - q.p2) * (2.0 / 3.0),
+            q.p2,
+        )
+    }
+}
+
+impl From<CubicHermite> for CubicBezier {
+    fn from(h: CubicHermite) -> Self {
+        Self::new(h.p0, h.p0 + h.m0 / 3.0, h.p1 - h.m1 / 3.0, h.p1)
+    }
+}
+
+impl From<CubicBezier> for CubicHermite {
+    fn from(b: CubicBezier) -> Self {
+        Self::new(b.p0, (b.p1 - b.p0) * 3.0, b.p3, (b.p3 - b.p2) * 3.0)
+    }
+}
+
+impl From<CatmullRom> for CubicHermite {
+    fn from(c: CatmullRom) -> Self {
+        c.to_hermite()
+    }
+}
+
+impl Curve for QuadraticBezier {
+    fn position(&self, t: f64) -> Vector3 {
+        QuadraticBezier::position(*self, t)
+    }
+
+    fn derivative(&self, t: f64) -> Vector3 {
+        QuadraticBezier::derivative(*self, t)
+    }
+
+    fn second_derivative(&self, t: f64) -> Vector3 {
+        QuadraticBezier::second_derivative(*self, t)
+    }
+}
+
+impl Curve for CubicBezier {
+    fn position(&self, t: f64) -> Vector3 {
+        CubicBezier::position(*self, t)
+    }
+
+    fn derivative(&self, t: f64) -> Vector3 {
+        CubicBezier::derivative(*self, t)
+    }
+
+    fn second_derivative(&self, t: f64) -> Vector3 {
+        CubicBezier::second_derivative(*self, t)
+    }
+}
+
+impl Curve for CubicHermite {
+    fn position(&self, t: f64) -> Vector3 {
+        CubicHermite::position(*self, t)
+    }
+
+    fn derivative(&self, t: f64) -> Vector3 {
+        CubicHermite::derivative(*self, t)
+    }
+
+    fn second_derivative(&self, t: f64) -> Vector3 {
+        CubicHermite::second_derivative(*self, t)
+    }
+}
+
+impl Curve for CatmullRom {
+    fn position(&self, t: f64) -> Vector3 {
+        CatmullRom::position(*self, t)
+    }
+
+    fn derivative(&self, t: f64) -> Vector3 {
+        CatmullRom::derivative(*self, t)
+    }
+
+    fn second_derivative(&self, t: f64) -> Vector3 {
+        CatmullRom::second_derivative(*self, t)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::Rng;
+    use std::f64::consts::{PI, TAU};
+
+    /// Checks both derivatives against central differences of their integrals.
+    fn check_derivatives(
+        position: impl Fn(f64) -> Vector3,
+        derivative: impl Fn(f64) -> Vector3,
+        second_derivative: impl Fn(f64) -> Vector3,
+    ) {
+        let h = 1e-5;
+        for i in 0..=10 {
+            let t = i as f64 / 10.0;
+            let d1 = (position(t + h) - position(t - h)) / (2.0 * h);
+            assert_vec_approx_eq!(derivative(t), d1, epsilon = 1e-6);
+            let d2 = (derivative(t + h) - derivative(t - h)) / (2.0 * h);
+            assert_vec_approx_eq!(second_derivative(t), d2, epsilon = 1e-6);
+        }
+    }
+
+    fn random_points<const N: usize>(rng: &mut Rng) -> [Vector3; N] {
+        std::array::from_fn(|_| rng.vector(-5.0, 5.0))
+    }
+
+    #[test]
+    fn test_bezier() {
+        let mut rng = Rng::new(22);
+        for _ in 0..20 {
+            let [p0, p1, p2, p3] = random_points(&mut rng);
+            let cubic = CubicBezier::new(p0, p1, p2, p3);
+            assert_eq!(cubic.position(0.0), p0);
+            assert_vec_approx_eq!(cubic.position(1.0), p3, epsilon = 1e-12);
+            assert_vec_approx_eq!(cubic.derivative(0.0), (p1 - p0) * 3.0);
+            check_derivatives(
+                |t| cubic.position(t),
+                |t| cubic.derivative(t),
+                |t| cubic.second_derivative(t),
+            );
+            // De Casteljau at the midpoint.
+            let (a, b, c) = (p0.lerp(p1, 0.5), p1.lerp(p2, 0.5), p2.lerp(p3, 0.5));
+            let mid = a.lerp(b, 0.5).lerp(b.lerp(c, 0.5), 0.5);
+            assert_vec_approx_eq!(cubic.position(0.5), mid, epsilon = 1e-12);
+
+            let quadratic = QuadraticBezier::new(p0, p1, p2);
+            assert_eq!(quadratic.position(0.0), p0);
+            assert_vec_approx_eq!(quadratic.position(1.0), p2, epsilon = 1e-12);
+            check_derivatives(
+                |t| quadratic.position(t),
+                |t| quadratic.derivative(t),
+                |t| quadratic.second_derivative(t),
+            );
+            let elevated = CubicBezier::from(quadratic);
+            for t in [0.1, 0.5, 0.8] {
+                assert_vec_approx_eq!(elevated.position(t), quadratic.position(t), epsilon = 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn test_hermite() {
+        let mut rng = Rng::new(23);
+        for _ in 0..20 {
+            let [p0, m0, p1, m1] = random_points(&mut rng);
+            let hermite = CubicHermite::new(p0, m0, p1, m1);
+            assert_eq!(hermite.position(0.0), p0);
+            assert_eq!(hermite.position(1.0), p1);
+            assert_eq!(hermite.derivative(0.0), m0);
+            assert_vec_approx_eq!(hermite.derivative(1.0), m1, epsilon = 1e-12);
+            check_derivatives(
+                |t| hermite.position(t),
+                |t| hermite.derivative(t),
+                |t| hermite.second_derivative(t),
+            );
+            let bezier = CubicBezier::from(hermite);
+            let back = CubicHermite::from(bezier);
+            assert_vec_approx_eq!(back.m0, m0, epsilon = 1e-12);
+            assert_vec_approx_eq!(back.m1, m1, epsilon = 1e-12);
+            for t in [0.2, 0.5, 0.9] {
+                assert_vec_approx_eq!(bezier.position(t), hermite.position(t), epsilon = 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn test_catmull_rom_uniform() {
+        let mut rng = Rng::new(24);
+        for _ in 0..20 {
+            let points = random_points(&mut rng);
+            let [p0, p1, p2, p3] = points;
+            let curve = CatmullRom::new(points, CatmullRomParameterization::Uniform);
+            // The classic matrix form.
+            for t in [0.0, 0.3, 0.5, 1.0] {
+                let (t2, t3) = (t * t, t * t * t);
+                let expected = (p1 * 2.0
+                    + (p2 - p0) * t
+                    + (p0 * 2.0 - p1 * 5.0 + p2 * 4.0 - p3) * t2
+                    + (-p0 + p1 * 3.0 - p2 * 3.0 + p3) * t3)
+                    * 0.5;
+                assert_vec_approx_eq!(curve.position(t), expected, epsilon = 1e-12);
+            }
+            assert_vec_approx_eq!(curve.derivative(0.0), (p2 - p0) * 0.5, epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_catmull_rom_parameterizations() {
+        let mut rng = Rng::new(25);
+        for parameterization in [
+            CatmullRomParameterization::Uniform,
+            CatmullRomParameterization::Centripetal,
+            CatmullRomParameterization::Chordal,
+        ] {
+            for _ in 0..20 {
+                let points = random_points(&mut rng);
+                let curve = CatmullRom::new(points, parameterization);
+                assert_eq!(curve.position(0.0), points[1]);
+                assert_vec_approx_eq!(curve.position(1.0), points[2], epsilon = 1e-12);
+                check_derivatives(
+                    |t| curve.position(t),
+                    |t| curve.derivative(t),
+                    |t| curve.second_derivative(t),
+                );
+            }
+
+            // Neighbouring segments share their tangent, scaled by the ratio
+            // of their knot intervals.
+            let points: [Vector3; 5] = random_points(&mut rng);
+            let first = CatmullRom::new(
+                [points[0], points[1], points[2], points[3]],
+                parameterization,
+            );
+            let second = CatmullRom::new(
+                [points[1], points[2], points[3], points[4]],
+                parameterization,
+            );
+            let alpha = parameterization.alpha();
+            let ratio =
+                ((points[3] - points[2]).length() / (points[2] - points[1]).length()).powf(alpha);
+            assert_vec_approx_eq!(
+                first.derivative(1.0) * ratio,
+                second.derivative(0.0),
+                epsilon = 1e-9
+            );
+        }
+    }
+
+    #[test]
+    fn test_catmull_rom_centripetal_no_cusp() {
+        // Uneven spacing makes the uniform spline overshoot into a cusp-like
+        // loop; the centripetal one stays monotone along x.
+        let points = [
+            Vector3::new(0.0, 0.0, 0.0),
+            Vector3::new(1.0, 1.0, 0.0),
+            Vector3::new(1.1, 1.0, 0.0),
+            Vector3::new(2.1, 0.0, 0.0),
+        ];
+        let forward =
+            |curve: CatmullRom| (0..=100).all(|i| curve.derivative(i as f64 / 100.0).x > 0.0);
+        assert!(!forward(CatmullRom::new(
+            points,
+            CatmullRomParameterization::Uniform
+        )));
+        assert!(forward(CatmullRom::new(
+            points,
+            CatmullRomParameterization::Centripetal
+        )));
+
+        // Repeated endpoints stay finite.
+        let repeated = [points[1], points[1], points[2], points[2]];
+        let curve = CatmullRom::new(repeated, CatmullRomParameterization::Chordal);
+        assert!(curve.position(0.5).is_finite());
+        assert!(curve.derivative(0.5).is_finite());
+    }
+
+    /// One turn of a helix of radius 1 rising `rise` per turn.
+    struct Helix {
+        rise: f64,
+    }
+
+    impl Curve for Helix {
+        fn position(&self, t: f64) -> Vector3 {
+            let (sin, cos) = (TAU * t).sin_cos();
+            Vector3::new(cos, sin, self.rise * t)
+        }
+
+        fn derivative(&self, t: f64) -> Vector3 {
+            let (sin, cos) = (TAU * t).sin_cos();
+            Vector3::new(-TAU * sin, TAU * cos, self.rise)
+        }
+
+        fn second_derivative(&self, t: f64) -> Vector3 {
+            let (sin, cos) = (TAU * t).sin_cos();
+            Vector3::new(-TAU * TAU * cos, -TAU * TAU * sin, 0.0)
+        }
+    }
+
+    fn assert_orthonormal(frame: CurveFrame) {
+        let CurveFrame {
+            tangent,
+            normal,
+            binormal,
+        } = frame;
+        for v in [tangent, normal, binormal] {
+            assert_vec_approx_eq!(v.length(), 1.0, epsilon = 1e-12);
+        }
+        assert_vec_approx_eq!(tangent.dot(normal), 0.0, epsilon = 1e-12);
+        assert_vec_approx_eq!(tangent.cross(normal), binormal, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_arc_length_table() {
+        let helix = Helix { rise: PI };
+        let table = ArcLengthTable::new(&helix, 8);
+        let length = (TAU * TAU + PI * PI).sqrt();
+        assert_vec_approx_eq!(table.length(), length, max_relative = 1e-12);
+        // Constant speed, so arc length is proportional to `t`.
+        assert_vec_approx_eq!(table.arc_length(0.3), 0.3 * length, max_relative = 1e-12);
+        assert_vec_approx_eq!(table.parameter_at(0.7 * length), 0.7, epsilon = 1e-12);
+        assert_eq!(table.parameter_at(-1.0), 0.0);
+        assert_eq!(table.parameter_at(2.0 * length), 1.0);
+        assert_eq!(table.arc_length(1.5), table.length());
+
+        // A straight line with uneven speed.
+        let line = CubicBezier::new(
+            Vector3::ZERO,
+            Vector3::new(0.1, 0.0, 0.0),
+            Vector3::new(0.2, 0.0, 0.0),
+            Vector3::new(3.0, 0.0, 0.0),
+        );
+        let table = ArcLengthTable::new(&line, 256);
+        assert_vec_approx_eq!(table.length(), 3.0, max_relative = 1e-12);
+        for i in 0..=10 {
+            let s = 0.3 * i as f64;
+            let t = table.parameter_at(s);
+            assert_vec_approx_eq!(table.arc_length(t), s, epsilon = 1e-12);
+            assert_vec_approx_eq!(line.position(t).x, s, epsilon = 1e-4);
+        }
+    }
+
+    #[test]
+    fn test_arc_length_table_stalled_curve() {
+        let point = Vector3::new(1.0, 2.0, 3.0);
+        let table = ArcLengthTable::new(&QuadraticBezier::new(point, point, point), 4);
+        assert_eq!(table.length(), 0.0);
+        assert_eq!(table.parameter_at(0.0), 0.0);
+        assert_eq!(table.parameter_at(1.0), 0.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "needs at least one segment")]
+    fn test_arc_length_table_no_segments() {
+        let _ = ArcLengthTable::new(&Helix { rise: 1.0 }, 0);
+    }
+
+    #[test]
+    fn test_frenet_frame() {
+        let helix = Helix { rise: PI };
+        for i in 0..10 {
+            let t = i as f64 / 10.0;
+            let frame = helix.frenet_frame(t).unwrap();
+            assert_orthonormal(frame);
+            // The normal of a helix points straight at its axis.
+            let p = helix.position(t);
+            assert_vec_approx_eq!(frame.normal, Vector3::new(-p.x, -p.y, 0.0), epsilon = 1e-12);
+        }
+
+        // A straight line with uneven speed, whose derivatives aren't exactly
+        // parallel after rounding.
+        let d = Vector3::new(0.3, 0.7, 1.1);
+        let line = CubicBezier::new(Vector3::ZERO, d * 0.1, d * 0.2, d * 3.0);
+        assert!((0..=10).any(|i| {
+            let t = i as f64 / 10.0;
+            line.derivative(t).cross(line.second_derivative(t)) != Vector3::ZERO
+        }));
+        for i in 0..=10 {
+            assert_eq!(
+                line.frenet_frame(i as f64 / 10.0),
+                Err(GeometryError::DegenerateVector)
+            );
+        }
+    }
+
+    #[test]
+    fn test_rotation_minimizing_frames() {
+        // Over one turn of a helix, parallel transport lags the Frenet frame
+        // by the integrated torsion, `TAU * b / sqrt(1 + b^2)` with
+        // `b = rise / TAU`.
+        let helix = Helix { rise: PI };
+        let parameters: Vec<f64> = (0..=1000).map(|i| i as f64 / 1000.0).collect();
+        let frames = helix.rotation_minimizing_frames(&parameters);
+        assert_eq!(frames.len(), parameters.len());
+        assert_eq!(frames[0], helix.frenet_frame(0.0).unwrap());
+        for frame in &frames {
+            assert_orthonormal(*frame);
+        }
+        let last = frames[1000];
+        let frenet = helix.frenet_frame(1.0).unwrap();
+        assert_vec_approx_eq!(last.tangent, frenet.tangent, epsilon = 1e-12);
+        let b = helix.rise / TAU;
+        let expected = TAU * b / (1.0 + b * b).sqrt();
+        assert_vec_approx_eq!(
+            last.normal.dot(frenet.normal).acos(),
+            expected,
+            epsilon = 1e-5
+        );
+
+        // On a straight line there is no Frenet frame and nothing to rotate.
+        let line = QuadraticBezier::new(
+            Vector3::ZERO,
+            Vector3::new(0.0, 0.0, 1.0),
+            Vector3::new(0.0, 0.0, 2.0),
+        );
+        let frames = line.rotation_minimizing_frames(&[0.0, 0.5, 1.0]);
+        assert_orthonormal(frames[0]);
+        assert!(frames.iter().all(|&frame| frame == frames[0]));
+        assert!(line.rotation_minimizing_frames(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_rotation_minimizing_frames_planar() {
+        // A planar S-bend: the Frenet normal flips at the inflection but the
+        // transported binormal stays on the plane normal.
+        let s_bend = CubicBezier::new(
+            Vector3::ZERO,
+            Vector3::new(1.0, 1.0, 0.0),
+            Vector3::new(2.0, -1.0, 0.0),
+            Vector3::new(3.0, 0.0, 0.0),
+        );
+        let parameters: Vec<f64> = (0..=100).map(|i| i as f64 / 100.0).collect();
+        let frames = s_bend.rotation_minimizing_frames(&parameters);
+        for frame in frames {
+            assert_vec_approx_eq!(
+                frame.binormal,
+                Vector3::new(0.0, 0.0, -1.0),
+                epsilon = 1e-12
+            );
+        }
+        assert_vec_approx_eq!(
+            s_bend.frenet_frame(1.0).unwrap().binormal,
+            Vector3::new(0.0, 0.0, 1.0),
+            epsilon = 1e-12
+        );
+    }
+}
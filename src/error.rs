@@ -0,0 +1,35 @@
+use std::error::Error;
+use std::fmt;
+
+/// Error returned by the crate's fallible geometric operations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GeometryError {
+    /// A vector was too short to define a direction.
+    DegenerateVector,
+    /// An input contained a NaN or infinite component.
+    NonFiniteComponent,
+    /// A matrix had no inverse.
+    SingularMatrix,
+    /// Fewer points were given than the operation needs.
+    TooFewPoints,
+    /// The points all lie on one line, or coincide.
+    CollinearPoints,
+    /// The points all lie in one plane.
+    CoplanarPoints,
+}
+
+impl fmt::Display for GeometryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let message = match self {
+            Self::DegenerateVector => "vector is too short to normalize",
+            Self::NonFiniteComponent => "vector has a non-finite component",
+            Self::SingularMatrix => "matrix is singular",
+            Self::TooFewPoints => "too few points",
+            Self::CollinearPoints => "points are collinear",
+            Self::CoplanarPoints => "points are coplanar",
+        };
+        f.write_str(message)
+    }
+}
+
+impl Error for GeometryError {}
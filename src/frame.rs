@@ -0,0 +1,462 @@
+use std::any::type_name;
+use std::fmt;
+use std::iter::Sum;
+use std::marker::PhantomData;
+use std::ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign};
+
+use crate::{Float, Point3, Quaternion, Scalar, Vector3};
+
+// `fn() -> F` keeps the tags free of auto-trait and drop-check effects, so any
+// type, including an uninhabited one, can name a frame.
+type Tag<F> = PhantomData<fn() -> F>;
+
+/// A `Vector3` tagged with the coordinate frame `F` it is expressed in.
+///
+/// Arithmetic is only defined between vectors of the same frame; a
+/// `FrameTransform` is the way to move a vector to another frame. Any type
+/// can serve as a frame tag.
+///
+/// ```compile_fail
+/// use vector3::FrameVector3;
+///
+/// struct World;
+/// struct Body;
+///
+/// let w = FrameVector3::<World>::new(1.0, 0.0, 0.0);
+/// let b = FrameVector3::<Body>::new(0.0, 1.0, 0.0);
+/// let _ = w + b;
+/// ```
+pub struct FrameVector3<F, T = f64> {
+    vector: Vector3<T>,
+    frame: Tag<F>,
+}
+
+/// A `Point3` tagged with the coordinate frame `F` it is expressed in.
+pub struct FramePoint3<F, T = f64> {
+    point: Point3<T>,
+    frame: Tag<F>,
+}
+
+/// A rigid transform, a rotation followed by a translation, taking
+/// coordinates in frame `From` to frame `To`.
+pub struct FrameTransform<From, To> {
+    /// Rotates `From` axes onto `To` axes.
+    pub rotation: Quaternion,
+    /// The origin of `From`, expressed in `To`.
+    pub translation: Vector3,
+    frames: PhantomData<fn(From) -> To>,
+}
+
+impl<F, T: Scalar> FrameVector3<F, T> {
+    pub const ZERO: Self = Self::from_vector(Vector3::ZERO);
+
+    pub fn new(x: T, y: T, z: T) -> Self {
+        Self::from_vector(Vector3::new(x, y, z))
+    }
+
+    /// Tags `vector` as being in frame `F`.
+    pub const fn from_vector(vector: Vector3<T>) -> Self {
+        Self {
+            vector,
+            frame: PhantomData,
+        }
+    }
+
+    /// The untagged vector.
+    pub fn to_vector(self) -> Vector3<T> {
+        self.vector
+    }
+
+    pub fn dot(self, other: Self) -> T {
+        self.vector.dot(other.vector)
+    }
+
+    pub fn cross(self, other: Self) -> Self {
+        Self::from_vector(self.vector.cross(other.vector))
+    }
+}
+
+impl<F, T: Float> FrameVector3<F, T> {
+    pub fn length(self) -> T {
+        self.vector.length()
+    }
+
+    /// Normalizes without checks, like `Vector3::normalized`.
+    pub fn normalized(self) -> Self {
+        Self::from_vector(self.vector.normalized())
+    }
+}
+
+impl<F, T: Scalar> FramePoint3<F, T> {
+    pub const ORIGIN: Self = Self::from_point(Point3::ORIGIN);
+
+    pub fn new(x: T, y: T, z: T) -> Self {
+        Self::from_point(Point3::new(x, y, z))
+    }
+
+    /// Tags `point` as being in frame `F`.
+    pub const fn from_point(point: Point3<T>) -> Self {
+        Self {
+            point,
+            frame: PhantomData,
+        }
+    }
+
+    /// The untagged point.
+    pub fn to_point(self) -> Point3<T> {
+        self.point
+    }
+
+    /// The displacement of this point from the frame's origin.
+    pub fn to_vector(self) -> FrameVector3<F, T> {
+        FrameVector3::from_vector(self.point.to_vector())
+    }
+}
+
+impl<F, T: Float> FramePoint3<F, T> {
+    pub fn distance(self, other: Self) -> T {
+        self.point.distance(other.point)
+    }
+}
+
+impl<From, To> FrameTransform<From, To> {
+    pub fn new(rotation: Quaternion, translation: Vector3) -> Self {
+        Self {
+            rotation,
+            translation,
+            frames: PhantomData,
+        }
+    }
+
+    /// A transform between frames that share their origin.
+    pub fn from_rotation(rotation: Quaternion) -> Self {
+        Self::new(rotation, Vector3::ZERO)
+    }
+
+    /// A transform between frames with parallel axes.
+    pub fn from_translation(translation: Vector3) -> Self {
+        Self::new(Quaternion::IDENTITY, translation)
+    }
+
+    /// Rotates a direction; translation doesn't apply to vectors.
+    pub fn transform_vector(self, v: FrameVector3<From>) -> FrameVector3<To> {
+        FrameVector3::from_vector(self.rotation.rotate(v.vector))
+    }
+
+    pub fn transform_point(self, p: FramePoint3<From>) -> FramePoint3<To> {
+        let v = self.rotation.rotate(p.point.to_vector()) + self.translation;
+        FramePoint3::from_point(Point3::from_vector(v))
+    }
+
+    /// The transform back from `To` to `From`. The rotation must be a unit
+    /// quaternion.
+    pub fn inverse(self) -> FrameTransform<To, From> {
+        let rotation = self.rotation.conjugate();
+        FrameTransform::new(rotation, -rotation.rotate(self.translation))
+    }
+
+    /// This transform followed by `next`.
+    pub fn then<Next>(self, next: FrameTransform<To, Next>) -> FrameTransform<From, Next> {
+        FrameTransform::new(
+            next.rotation * self.rotation,
+            next.rotation.rotate(self.translation) + next.translation,
+        )
+    }
+}
+
+impl<F> FrameTransform<F, F> {
+    pub fn identity() -> Self {
+        Self::new(Quaternion::IDENTITY, Vector3::ZERO)
+    }
+}
+
+// Manual impls, since derives would require the tags to implement the traits.
+
+impl<F, T: Copy> Clone for FrameVector3<F, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<F, T: Copy> Copy for FrameVector3<F, T> {}
+
+impl<F, T: PartialEq> PartialEq for FrameVector3<F, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.vector == other.vector
+    }
+}
+
+impl<F, T: fmt::Debug> fmt::Debug for FrameVector3<F, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FrameVector3")
+            .field("frame", &type_name::<F>())
+            .field("x", &self.vector.x)
+            .field("y", &self.vector.y)
+            .field("z", &self.vector.z)
+            .finish()
+    }
+}
+
+impl<F, T: Scalar> Default for FrameVector3<F, T> {
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl<F, T: Copy> Clone for FramePoint3<F, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<F, T: Copy> Copy for FramePoint3<F, T> {}
+
+impl<F, T: PartialEq> PartialEq for FramePoint3<F, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.point == other.point
+    }
+}
+
+impl<F, T: fmt::Debug> fmt::Debug for FramePoint3<F, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FramePoint3")
+            .field("frame", &type_name::<F>())
+            .field("x", &self.point.x)
+            .field("y", &self.point.y)
+            .field("z", &self.point.z)
+            .finish()
+    }
+}
+
+impl<From, To> Clone for FrameTransform<From, To> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<From, To> Copy for FrameTransform<From, To> {}
+
+impl<From, To> PartialEq for FrameTransform<From, To> {
+    fn eq(&self, other: &Self) -> bool {
+        self.rotation == other.rotation && self.translation == other.translation
+    }
+}
+
+impl<From, To> fmt::Debug for FrameTransform<From, To> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FrameTransform")
+            .field("from", &type_name::<From>())
+            .field("to", &type_name::<To>())
+            .field("rotation", &self.rotation)
+            .field("translation", &self.translation)
+            .finish()
+    }
+}
+
+impl<F, T: Scalar> Add for FrameVector3<F, T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::from_vector(self.vector + other.vector)
+    }
+}
+
+impl<F, T: Scalar> Sub for FrameVector3<F, T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::from_vector(self.vector - other.vector)
+    }
+}
+
+impl<F, T: Scalar> Neg for FrameVector3<F, T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::from_vector(-self.vector)
+    }
+}
+
+impl<F, T: Scalar> Mul<T> for FrameVector3<F, T> {
+    type Output = Self;
+
+    fn mul(self, other: T) -> Self {
+        Self::from_vector(self.vector * other)
+    }
+}
+
+impl<F> Mul<FrameVector3<F>> for f64 {
+    type Output = FrameVector3<F>;
+
+    fn mul(self, other: FrameVector3<F>) -> FrameVector3<F> {
+        other * self
+    }
+}
+
+impl<F, T: Float> Div<T> for FrameVector3<F, T> {
+    type Output = Self;
+
+    fn div(self, other: T) -> Self {
+        Self::from_vector(self.vector / other)
+    }
+}
+
+impl<F, T: Scalar> AddAssign for FrameVector3<F, T> {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl<F, T: Scalar> SubAssign for FrameVector3<F, T> {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl<F, T: Scalar> Sum for FrameVector3<F, T> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, Add::add)
+    }
+}
+
+impl<F, T: Scalar> Sub for FramePoint3<F, T> {
+    type Output = FrameVector3<F, T>;
+
+    fn sub(self, other: Self) -> FrameVector3<F, T> {
+        FrameVector3::from_vector(self.point - other.point)
+    }
+}
+
+impl<F, T: Scalar> Add<FrameVector3<F, T>> for FramePoint3<F, T> {
+    type Output = Self;
+
+    fn add(self, other: FrameVector3<F, T>) -> Self {
+        Self::from_point(self.point + other.vector)
+    }
+}
+
+impl<F, T: Scalar> Sub<FrameVector3<F, T>> for FramePoint3<F, T> {
+    type Output = Self;
+
+    fn sub(self, other: FrameVector3<F, T>) -> Self {
+        Self::from_point(self.point - other.vector)
+    }
+}
+
+impl<From, To> Mul<FrameVector3<From>> for FrameTransform<From, To> {
+    type Output = FrameVector3<To>;
+
+    fn mul(self, other: FrameVector3<From>) -> FrameVector3<To> {
+        self.transform_vector(other)
+    }
+}
+
+impl<From, To> Mul<FramePoint3<From>> for FrameTransform<From, To> {
+    type Output = FramePoint3<To>;
+
+    fn mul(self, other: FramePoint3<From>) -> FramePoint3<To> {
+        self.transform_point(other)
+    }
+}
+
+/// Composition: `b_to_c * a_to_b` takes `A` to `C`.
+impl<A, B, C> Mul<FrameTransform<A, B>> for FrameTransform<B, C> {
+    type Output = FrameTransform<A, C>;
+
+    fn mul(self, other: FrameTransform<A, B>) -> FrameTransform<A, C> {
+        other.then(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f64::consts::FRAC_PI_2;
+
+    struct World;
+    struct Body;
+    enum Sensor {}
+
+    fn body_to_world() -> FrameTransform<Body, World> {
+        // The body sits at (10, 0, 0), turned a quarter turn about z.
+        FrameTransform::new(
+            Quaternion::from_axis_angle(Vector3::new(0.0, 0.0, 1.0), FRAC_PI_2),
+            Vector3::new(10.0, 0.0, 0.0),
+        )
+    }
+
+    #[test]
+    fn test_frame_vector_arithmetic() {
+        let a = FrameVector3::<World>::new(1.0, 2.0, 3.0);
+        let b = FrameVector3::<World>::new(4.0, 5.0, 6.0);
+        assert_eq!((a + b).to_vector(), Vector3::new(5.0, 7.0, 9.0));
+        assert_eq!(b - a, FrameVector3::new(3.0, 3.0, 3.0));
+        assert_eq!(2.0 * a, a * 2.0);
+        assert_eq!(a.dot(b), 32.0);
+        assert_eq!(a.cross(b), FrameVector3::new(-3.0, 6.0, -3.0));
+        assert_eq!([a, b].into_iter().sum::<FrameVector3<World>>(), a + b);
+        let mut c = a;
+        c -= b;
+        c += b;
+        assert_eq!(c, a);
+        assert_eq!(
+            format!("{:?}", FrameVector3::<Sensor>::ZERO),
+            format!(
+                "FrameVector3 {{ frame: \"{}\", x: 0.0, y: 0.0, z: 0.0 }}",
+                type_name::<Sensor>()
+            )
+        );
+    }
+
+    #[test]
+    fn test_frame_points() {
+        let p = FramePoint3::<Body>::new(1.0, 1.0, 1.0);
+        let q = FramePoint3::<Body>::new(4.0, 5.0, 1.0);
+        assert_eq!(q - p, FrameVector3::new(3.0, 4.0, 0.0));
+        assert_eq!(p + (q - p), q);
+        assert_eq!(q - (q - p), p);
+        assert_eq!(p.distance(q), 5.0);
+    }
+
+    #[test]
+    fn test_frame_transform() {
+        let to_world = body_to_world();
+        let nose = FramePoint3::<Body>::new(1.0, 0.0, 0.0);
+        assert_vec_approx_eq!(
+            to_world * nose,
+            FramePoint3::new(10.0, 1.0, 0.0),
+            epsilon = 1e-12
+        );
+        // Vectors rotate but don't translate.
+        let forward = FrameVector3::<Body>::new(1.0, 0.0, 0.0);
+        assert_vec_approx_eq!(
+            to_world * forward,
+            FrameVector3::new(0.0, 1.0, 0.0),
+            epsilon = 1e-12
+        );
+
+        let to_body = to_world.inverse();
+        let back = to_body * (to_world * nose);
+        assert_vec_approx_eq!(back, nose, epsilon = 1e-12);
+
+        let round_trip: FrameTransform<Body, Body> = to_body * to_world;
+        assert_vec_approx_eq!(round_trip.rotation, Quaternion::IDENTITY, epsilon = 1e-12);
+        assert_vec_approx_eq!(round_trip.translation, Vector3::ZERO, epsilon = 1e-12);
+        assert_eq!(
+            FrameTransform::<World, World>::identity().transform_point(FramePoint3::ORIGIN),
+            FramePoint3::ORIGIN
+        );
+    }
+
+    #[test]
+    fn test_frame_transform_chain() {
+        let sensor_to_body =
+            FrameTransform::<Sensor, Body>::from_translation(Vector3::new(0.0, 0.0, 2.0));
+        let sensor_to_world = sensor_to_body.then(body_to_world());
+        let reading = FramePoint3::<Sensor>::new(1.0, 0.0, 0.0);
+        let direct = sensor_to_world * reading;
+        let stepwise = body_to_world() * (sensor_to_body * reading);
+        assert_vec_approx_eq!(direct, stepwise, epsilon = 1e-12);
+        assert_vec_approx_eq!(direct, FramePoint3::new(10.0, 1.0, 2.0), epsilon = 1e-12);
+    }
+}
@@ -0,0 +1,320 @@
+use crate::{Matrix3, Vector3};
+
+/// A reference ellipsoid of revolution about the z axis.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Ellipsoid {
+    /// The equatorial radius in metres.
+    pub semi_major_axis: f64,
+    pub flattening: f64,
+}
+
+/// A geodetic position: latitude and longitude in radians and the height
+/// above the ellipsoid in metres.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Lla {
+    pub latitude: f64,
+    pub longitude: f64,
+    pub altitude: f64,
+}
+
+impl Ellipsoid {
+    pub const WGS84: Self = Self::new(6_378_137.0, 1.0 / 298.257_223_563);
+    pub const GRS80: Self = Self::new(6_378_137.0, 1.0 / 298.257_222_101);
+
+    pub const fn new(semi_major_axis: f64, flattening: f64) -> Self {
+        Self {
+            semi_major_axis,
+            flattening,
+        }
+    }
+
+    /// The polar radius in metres.
+    pub fn semi_minor_axis(self) -> f64 {
+        self.semi_major_axis * (1.0 - self.flattening)
+    }
+
+    /// The square of the first eccentricity.
+    pub fn eccentricity_squared(self) -> f64 {
+        self.flattening * (2.0 - self.flattening)
+    }
+
+    /// Earth-centred, earth-fixed coordinates of `lla`, in metres.
+    pub fn lla_to_ecef(self, lla: Lla) -> Vector3 {
+        let (sin_lat, cos_lat) = lla.latitude.sin_cos();
+        let (sin_lon, cos_lon) = lla.longitude.sin_cos();
+        let e2 = self.eccentricity_squared();
+        // The prime vertical radius of curvature.
+        let n = self.semi_major_axis / (1.0 - e2 * sin_lat * sin_lat).sqrt();
+        Vector3::new(
+            (n + lla.altitude) * cos_lat * cos_lon,
+            (n + lla.altitude) * cos_lat * sin_lon,
+            (n * (1.0 - e2) + lla.altitude) * sin_lat,
+        )
+    }
+
+    /// The geodetic position of an ECEF point, using Bowring's iteration.
+    ///
+    /// Three iterations keep the error far below a millimetre for positions
+    /// near the Earth's surface. Longitude is zero on the polar axis.
+    pub fn ecef_to_lla(self, ecef: Vector3) -> Lla {
+        let a = self.semi_major_axis;
+        let b = self.semi_minor_axis();
+        let e2 = self.eccentricity_squared();
+        let second_e2 = e2 / (1.0 - e2);
+        let p = ecef.x.hypot(ecef.y);
+        let longitude = if p == 0.0 { 0.0 } else { ecef.y.atan2(ecef.x) };
+
+        // Iterate on the reduced latitude.
+        let mut reduced = ecef.z.atan2((1.0 - self.flattening) * p);
+        let mut latitude = reduced;
+        for _ in 0..3 {
+            let (sin, cos) = reduced.sin_cos();
+            latitude = (ecef.z + second_e2 * b * sin.powi(3)).atan2(p - e2 * a * cos.powi(3));
+            reduced = ((1.0 - self.flattening) * latitude.sin()).atan2(latitude.cos());
+        }
+
+        let (sin_lat, cos_lat) = latitude.sin_cos();
+        let altitude = p * cos_lat + ecef.z * sin_lat - a * (1.0 - e2 * sin_lat * sin_lat).sqrt();
+        Lla {
+            latitude,
+            longitude,
+            altitude,
+        }
+    }
+}
+
+impl Default for Ellipsoid {
+    fn default() -> Self {
+        Self::WGS84
+    }
+}
+
+impl Lla {
+    pub fn new(latitude: f64, longitude: f64, altitude: f64) -> Self {
+        Self {
+            latitude,
+            longitude,
+            altitude,
+        }
+    }
+
+    pub fn from_degrees(latitude: f64, longitude: f64, altitude: f64) -> Self {
+        Self::new(latitude.to_radians(), longitude.to_radians(), altitude)
+    }
+
+    /// ECEF coordinates on the WGS84 ellipsoid.
+    pub fn to_ecef(self) -> Vector3 {
+        Ellipsoid::WGS84.lla_to_ecef(self)
+    }
+
+    /// The position of an ECEF point on the WGS84 ellipsoid.
+    pub fn from_ecef(ecef: Vector3) -> Self {
+        Ellipsoid::WGS84.ecef_to_lla(ecef)
+    }
+}
+
+/// A local tangent plane frame at a reference position, converting between
+/// ECEF and east-north-up (ENU) or north-east-down (NED) coordinates in
+/// metres relative to the reference.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LocalTangentFrame {
+    ellipsoid: Ellipsoid,
+    reference: Lla,
+    origin: Vector3,
+    /// Rotates ECEF offsets into ENU.
+    ecef_to_enu: Matrix3,
+}
+
+impl LocalTangentFrame {
+    /// A frame at `reference` on the WGS84 ellipsoid.
+    pub fn new(reference: Lla) -> Self {
+        Self::with_ellipsoid(reference, Ellipsoid::WGS84)
+    }
+
+    pub fn with_ellipsoid(reference: Lla, ellipsoid: Ellipsoid) -> Self {
+        let (sin_lat, cos_lat) = reference.latitude.sin_cos();
+        let (sin_lon, cos_lon) = reference.longitude.sin_cos();
+        let east = Vector3::new(-sin_lon, cos_lon, 0.0);
+        let north = Vector3::new(-sin_lat * cos_lon, -sin_lat * sin_lon, cos_lat);
+        let up = Vector3::new(cos_lat * cos_lon, cos_lat * sin_lon, sin_lat);
+        Self {
+            ellipsoid,
+            reference,
+            origin: ellipsoid.lla_to_ecef(reference),
+            ecef_to_enu: Matrix3::from_rows(east, north, up),
+        }
+    }
+
+    pub fn reference(self) -> Lla {
+        self.reference
+    }
+
+    pub fn ellipsoid(self) -> Ellipsoid {
+        self.ellipsoid
+    }
+
+    pub fn ecef_to_enu(self, ecef: Vector3) -> Vector3 {
+        self.ecef_to_enu * (ecef - self.origin)
+    }
+
+    pub fn enu_to_ecef(self, enu: Vector3) -> Vector3 {
+        self.origin + self.ecef_to_enu.transpose() * enu
+    }
+
+    pub fn ecef_to_ned(self, ecef: Vector3) -> Vector3 {
+        enu_ned(self.ecef_to_enu(ecef))
+    }
+
+    pub fn ned_to_ecef(self, ned: Vector3) -> Vector3 {
+        self.enu_to_ecef(enu_ned(ned))
+    }
+
+    pub fn lla_to_enu(self, lla: Lla) -> Vector3 {
+        self.ecef_to_enu(self.ellipsoid.lla_to_ecef(lla))
+    }
+
+    pub fn enu_to_lla(self, enu: Vector3) -> Lla {
+        self.ellipsoid.ecef_to_lla(self.enu_to_ecef(enu))
+    }
+
+    pub fn lla_to_ned(self, lla: Lla) -> Vector3 {
+        self.ecef_to_ned(self.ellipsoid.lla_to_ecef(lla))
+    }
+
+    pub fn ned_to_lla(self, ned: Vector3) -> Lla {
+        self.ellipsoid.ecef_to_lla(self.ned_to_ecef(ned))
+    }
+}
+
+/// Swaps between ENU and NED, which is its own inverse.
+fn enu_ned(v: Vector3) -> Vector3 {
+    Vector3::new(v.y, v.x, -v.z)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::Rng;
+
+    const MILLIMETRE: f64 = 1e-3;
+
+    fn random_lla(rng: &mut Rng) -> Lla {
+        Lla::from_degrees(
+            rng.range(-90.0, 90.0),
+            rng.range(-180.0, 180.0),
+            rng.range(-500.0, 100_000.0),
+        )
+    }
+
+    #[test]
+    fn test_lla_to_ecef_known_points() {
+        let wgs84 = Ellipsoid::WGS84;
+        let equator = Lla::from_degrees(0.0, 0.0, 0.0).to_ecef();
+        assert_eq!(equator, Vector3::new(6_378_137.0, 0.0, 0.0));
+        let pole = Lla::from_degrees(90.0, 0.0, 100.0).to_ecef();
+        assert_vec_approx_eq!(
+            pole,
+            Vector3::new(0.0, 0.0, wgs84.semi_minor_axis() + 100.0),
+            epsilon = MILLIMETRE
+        );
+        assert_vec_approx_eq!(wgs84.semi_minor_axis(), 6_356_752.314_245, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn test_ecef_lla_round_trip() {
+        let mut rng = Rng::new(20);
+        for _ in 0..10_000 {
+            let lla = random_lla(&mut rng);
+            let ecef = lla.to_ecef();
+            let back = Lla::from_ecef(ecef);
+            assert_vec_approx_eq!(back.altitude, lla.altitude, epsilon = MILLIMETRE);
+            assert_vec_approx_eq!(back.to_ecef(), ecef, epsilon = MILLIMETRE);
+        }
+    }
+
+    #[test]
+    fn test_ecef_to_lla_poles() {
+        let b = Ellipsoid::WGS84.semi_minor_axis();
+        let north = Lla::from_ecef(Vector3::new(0.0, 0.0, b + 250.0));
+        assert_eq!(north.latitude, std::f64::consts::FRAC_PI_2);
+        assert_eq!(north.longitude, 0.0);
+        assert_vec_approx_eq!(north.altitude, 250.0, epsilon = MILLIMETRE);
+        let south = Lla::from_ecef(Vector3::new(0.0, 0.0, -b));
+        assert_eq!(south.latitude, -std::f64::consts::FRAC_PI_2);
+        assert_vec_approx_eq!(south.altitude, 0.0, epsilon = MILLIMETRE);
+    }
+
+    #[test]
+    fn test_other_ellipsoids() {
+        // On a sphere geodetic and geocentric latitude agree.
+        let sphere = Ellipsoid::new(1000.0, 0.0);
+        let lla = sphere.ecef_to_lla(Vector3::new(600.0, 0.0, 800.0) * 2.0);
+        assert_vec_approx_eq!(lla.latitude, 0.8f64.asin(), epsilon = 1e-12);
+        assert_vec_approx_eq!(lla.altitude, 1000.0, epsilon = 1e-9);
+
+        let grs80 = Ellipsoid::GRS80;
+        let lla = Lla::from_degrees(-33.9, 151.2, 58.0);
+        let back = grs80.ecef_to_lla(grs80.lla_to_ecef(lla));
+        assert_vec_approx_eq!(back.altitude, lla.altitude, epsilon = MILLIMETRE);
+        // The ellipsoids differ by about 0.1 mm at this latitude.
+        let difference = grs80.lla_to_ecef(lla) - lla.to_ecef();
+        assert!(difference.length() < MILLIMETRE);
+    }
+
+    #[test]
+    fn test_local_tangent_frame() {
+        let reference = Lla::from_degrees(47.397_742, 8.545_594, 488.0);
+        let frame = LocalTangentFrame::new(reference);
+        assert_eq!(frame.ecef_to_enu(reference.to_ecef()), Vector3::ZERO);
+
+        let above = Lla {
+            altitude: 588.0,
+            ..reference
+        };
+        assert_vec_approx_eq!(
+            frame.lla_to_enu(above),
+            Vector3::new(0.0, 0.0, 100.0),
+            epsilon = MILLIMETRE
+        );
+        assert_vec_approx_eq!(
+            frame.lla_to_ned(above),
+            Vector3::new(0.0, 0.0, -100.0),
+            epsilon = MILLIMETRE
+        );
+
+        // A little north-east of the reference.
+        let enu = frame.lla_to_enu(Lla::from_degrees(47.398, 8.546, 488.0));
+        assert!(enu.x > 0.0 && enu.y > 0.0 && enu.z < 0.0);
+        let ned = frame.lla_to_ned(Lla::from_degrees(47.398, 8.546, 488.0));
+        assert_eq!(ned, Vector3::new(enu.y, enu.x, -enu.z));
+    }
+
+    #[test]
+    fn test_local_tangent_frame_round_trip() {
+        let mut rng = Rng::new(21);
+        for _ in 0..1000 {
+            let frame = LocalTangentFrame::new(random_lla(&mut rng));
+            let local = rng.vector(-50_000.0, 50_000.0);
+            assert_vec_approx_eq!(
+                frame.ecef_to_enu(frame.enu_to_ecef(local)),
+                local,
+                epsilon = MILLIMETRE
+            );
+            assert_vec_approx_eq!(
+                frame.ecef_to_ned(frame.ned_to_ecef(local)),
+                local,
+                epsilon = MILLIMETRE
+            );
+            assert_vec_approx_eq!(
+                frame.lla_to_enu(frame.enu_to_lla(local)),
+                local,
+                epsilon = MILLIMETRE
+            );
+            assert_vec_approx_eq!(
+                frame.lla_to_ned(frame.ned_to_lla(local)),
+                local,
+                epsilon = MILLIMETRE
+            );
+        }
+    }
+}
@@ -0,0 +1,505 @@
+use crate::Vector3;
+
+/// The position and velocity of a particle.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct ParticleState {
+    pub position: Vector3,
+    pub velocity: Vector3,
+}
+
+/// The fixed-step integrators.
+///
+/// Each advances a `ParticleState` by `dt` under an acceleration given as a
+/// function of time and state.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FixedStep {
+    /// Updates the velocity, then moves with the new velocity. First order,
+    /// but symplectic, so energy errors stay bounded instead of drifting.
+    SemiImplicitEuler,
+    /// Second order and symplectic. Takes two acceleration evaluations per
+    /// step, and is only symplectic when the acceleration doesn't depend on
+    /// velocity.
+    VelocityVerlet,
+    /// The classic fourth-order Runge-Kutta method. Accurate, but energy
+    /// slowly drifts over long runs.
+    Rk4,
+}
+
+/// The adaptive Dormand-Prince 5(4) Runge-Kutta integrator.
+///
+/// Each step is accepted when its estimated local error is within
+/// `absolute_tolerance + relative_tolerance * |y|` in the root-mean-square
+/// over the six position and velocity components, and the step size is
+/// adapted from that estimate. Time only runs forwards.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DormandPrince {
+    pub absolute_tolerance: f64,
+    pub relative_tolerance: f64,
+    /// Steps are never shrunk below this; a step that still fails the
+    /// tolerance at this size is accepted anyway.
+    pub min_step: f64,
+    pub max_step: f64,
+    step: f64,
+}
+
+impl ParticleState {
+    pub fn new(position: Vector3, velocity: Vector3) -> Self {
+        Self { position, velocity }
+    }
+
+    /// The time derivative: velocity and acceleration.
+    fn rate(self, t: f64, acceleration: &mut impl FnMut(f64, Self) -> Vector3) -> Self {
+        Self::new(self.velocity, acceleration(t, self))
+    }
+
+    /// `self + h * Σ weight * rate`.
+    fn advance(self, h: f64, terms: impl IntoIterator<Item = (f64, Self)>) -> Self {
+        let mut state = self;
+        for (weight, rate) in terms {
+            state.position += rate.position * (h * weight);
+            state.velocity += rate.velocity * (h * weight);
+        }
+        state
+    }
+}
+
+impl FixedStep {
+    /// Advances `state` from time `t` to `t + dt`.
+    pub fn step(
+        self,
+        state: ParticleState,
+        t: f64,
+        dt: f64,
+        mut acceleration: impl FnMut(f64, ParticleState) -> Vector3,
+    ) -> ParticleState {
+        let ParticleState { position, velocity } = state;
+        match self {
+            Self::SemiImplicitEuler => {
+                let velocity = velocity + acceleration(t, state) * dt;
+                ParticleState::new(position + velocity * dt, velocity)
+            }
+            Self::VelocityVerlet => {
+                let a0 = acceleration(t, state);
+                let half = velocity + a0 * (dt / 2.0);
+                let position = position + half * dt;
+                let a1 = acceleration(t + dt, ParticleState::new(position, half));
+                ParticleState::new(position, half + a1 * (dt / 2.0))
+            }
+            Self::Rk4 => {
+                let k1 = state.rate(t, &mut acceleration);
+                let k2 = state
+                    .advance(dt / 2.0, [(1.0, k1)])
+                    .rate(t + dt / 2.0, &mut acceleration);
+                let k3 = state
+                    .advance(dt / 2.0, [(1.0, k2)])
+                    .rate(t + dt / 2.0, &mut acceleration);
+                let k4 = state
+                    .advance(dt, [(1.0, k3)])
+                    .rate(t + dt, &mut acceleration);
+                state.advance(
+                    dt,
+                    [
+                        (1.0 / 6.0, k1),
+                        (1.0 / 3.0, k2),
+                        (1.0 / 3.0, k3),
+                        (1.0 / 6.0, k4),
+                    ],
+                )
+            }
+        }
+    }
+
+    /// Advances `state` from `t0` to `t1` in equal steps no longer than
+    /// `max_dt`.
+    ///
+    /// Panics unless `max_dt` is positive and finite and `t1 >= t0`.
+    pub fn integrate(
+        self,
+        state: ParticleState,
+        t0: f64,
+        t1: f64,
+        max_dt: f64,
+        mut acceleration: impl FnMut(f64, ParticleState) -> Vector3,
+    ) -> ParticleState {
+        assert!(
+            max_dt > 0.0 && max_dt.is_finite(),
+            "FixedStep::integrate needs a positive, finite max_dt"
+        );
+        assert!(t1 >= t0, "FixedStep::integrate needs t1 >= t0");
+        let steps = ((t1 - t0) / max_dt).ceil().max(1.0) as usize;
+        let dt = (t1 - t0) / steps as f64;
+        (0..steps).fold(state, |state, i| {
+            self.step(state, t0 + i as f64 * dt, dt, &mut acceleration)
+        })
+    }
+}
+
+// The Dormand-Prince tableau. The last row of `A` doubles as the fifth-order
+// weights, and `E` is the difference from the embedded fourth-order weights.
+const C: [f64; 7] = [0.0, 1.0 / 5.0, 3.0 / 10.0, 4.0 / 5.0, 8.0 / 9.0, 1.0, 1.0];
+const A: [[f64; 6]; 7] = [
+    [0.0; 6],
+    [1.0 / 5.0, 0.0, 0.0, 0.0, 0.0, 0.0],
+    [3.0 / 40.0, 9.0 / 40.0, 0.0, 0.0, 0.0, 0.0],
+    [44.0 / 45.0, -56.0 / 15.0, 32.0 / 9.0, 0.0, 0.0, 0.0],
+    [
+        19372.0 / 6561.0,
+        -25360.0 / 2187.0,
+        64448.0 / 6561.0,
+        -212.0 / 729.0,
+        0.0,
+        0.0,
+    ],
+    [
+        9017.0 / 3168.0,
+        -355.0 / 33.0,
+        46732.0 / 5247.0,
+        49.0 / 176.0,
+        -5103.0 / 18656.0,
+        0.0,
+    ],
+    [
+        35.0 / 384.0,
+        0.0,
+        500.0 / 1113.0,
+        125.0 / 192.0,
+        -2187.0 / 6784.0,
+        11.0 / 84.0,
+    ],
+];
+const E: [f64; 7] = [
+    71.0 / 57600.0,
+    0.0,
+    -71.0 / 16695.0,
+    71.0 / 1920.0,
+    -17253.0 / 339200.0,
+    22.0 / 525.0,
+    -1.0 / 40.0,
+];
+
+impl DormandPrince {
+    /// An integrator starting with steps of `initial_step` and using
+    /// `tolerance` as both the absolute and relative tolerance.
+    ///
+    /// Panics unless `initial_step` is positive and finite.
+    pub fn new(initial_step: f64, tolerance: f64) -> Self {
+        assert!(
+            initial_step > 0.0 && initial_step.is_finite(),
+            "DormandPrince::new needs a positive, finite initial_step"
+        );
+        Self {
+            absolute_tolerance: tolerance,
+            relative_tolerance: tolerance,
+            min_step: initial_step * 1e-12,
+            max_step: f64::INFINITY,
+            step: initial_step,
+        }
+    }
+
+    /// The size the next step will try.
+    pub fn step_size(&self) -> f64 {
+        self.step
+    }
+
+    /// Takes one accepted step from time `t`, returning its length and the
+    /// new state.
+    pub fn step(
+        &mut self,
+        state: ParticleState,
+        t: f64,
+        mut acceleration: impl FnMut(f64, ParticleState) -> Vector3,
+    ) -> (f64, ParticleState) {
+        self.step_at_most(state, t, f64::INFINITY, &mut acceleration)
+    }
+
+    /// Advances `state` from `t0` to exactly `t1`.
+    ///
+    /// Panics unless `t1 >= t0`.
+    pub fn integrate(
+        &mut self,
+        mut state: ParticleState,
+        t0: f64,
+        t1: f64,
+        mut acceleration: impl FnMut(f64, ParticleState) -> Vector3,
+    ) -> ParticleState {
+        assert!(t1 >= t0, "DormandPrince::integrate needs t1 >= t0");
+        let mut t = t0;
+        while t < t1 {
+            let (dt, next) = self.step_at_most(state, t, t1 - t, &mut acceleration);
+            // Land exactly on `t1` rather than a rounding error short of it.
+            t = if dt == t1 - t { t1 } else { t + dt };
+            state = next;
+        }
+        state
+    }
+
+    fn step_at_most(
+        &mut self,
+        state: ParticleState,
+        t: f64,
+        limit: f64,
+        acceleration: &mut impl FnMut(f64, ParticleState) -> Vector3,
+    ) -> (f64, ParticleState) {
+        loop {
+            let h = self.step.min(limit);
+            let mut k = [ParticleState::default(); 7];
+            for i in 0..7 {
+                let stage = state.advance(h, A[i][..i].iter().copied().zip(k));
+                k[i] = stage.rate(t + C[i] * h, acceleration);
+            }
+            let next = state.advance(h, A[6].into_iter().zip(k));
+            let error = ParticleState::default().advance(h, E.into_iter().zip(k));
+            let error = self.error_norm(state, next, error);
+
+            let factor = if error == 0.0 {
+                5.0
+            } else if error.is_nan() {
+                0.2
+            } else {
+                (0.9 * error.powf(-0.2)).clamp(0.2, 5.0)
+            };
+            let accepted = error <= 1.0 || h <= self.min_step;
+            // A step cut short by `limit` says nothing about a larger step.
+            if !accepted || h == self.step {
+                self.step = (h * factor).clamp(self.min_step, self.max_step);
+            }
+            if accepted {
+                return (h, next);
+            }
+        }
+    }
+
+    /// The root-mean-square of the error relative to the tolerance, so that
+    /// steps with an error up to 1 are accepted.
+    fn error_norm(self, from: ParticleState, to: ParticleState, error: ParticleState) -> f64 {
+        let components = |s: ParticleState| {
+            [
+                s.position.x,
+                s.position.y,
+                s.position.z,
+                s.velocity.x,
+                s.velocity.y,
+                s.velocity.z,
+            ]
+        };
+        let sum: f64 = components(from)
+            .into_iter()
+            .zip(components(to))
+            .zip(components(error))
+            .map(|((a, b), e)| {
+                let scale =
+                    self.absolute_tolerance + self.relative_tolerance * a.abs().max(b.abs());
+                (e / scale).powi(2)
+            })
+            .sum();
+        (sum / 6.0).sqrt()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f64::consts::TAU;
+
+    fn spring(_t: f64, s: ParticleState) -> Vector3 {
+        -s.position
+    }
+
+    fn spring_energy(s: ParticleState) -> f64 {
+        0.5 * (s.velocity.dot(s.velocity) + s.position.dot(s.position))
+    }
+
+    fn gravity(_t: f64, s: ParticleState) -> Vector3 {
+        let r = s.position.length();
+        -s.position / (r * r * r)
+    }
+
+    fn orbit_energy(s: ParticleState) -> f64 {
+        0.5 * s.velocity.dot(s.velocity) - 1.0 / s.position.length()
+    }
+
+    /// An orbit with eccentricity 0.5 and semi-major axis 2, starting at
+    /// periapsis.
+    fn periapsis() -> ParticleState {
+        // Tilted out of the xy-plane to exercise all three components.
+        let direction = Vector3::new(0.0, 2.0, 1.0).normalized();
+        ParticleState::new(Vector3::new(1.0, 0.0, 0.0), direction * 1.5f64.sqrt())
+    }
+
+    /// The largest relative energy error over each of `chunks` stretches
+    /// of `steps` fixed steps.
+    fn energy_errors(
+        method: FixedStep,
+        start: ParticleState,
+        dt: f64,
+        steps: usize,
+        chunks: usize,
+        acceleration: fn(f64, ParticleState) -> Vector3,
+        energy: fn(ParticleState) -> f64,
+    ) -> Vec<f64> {
+        let e0 = energy(start);
+        let mut state = start;
+        let mut t = 0.0;
+        (0..chunks)
+            .map(|_| {
+                let mut worst: f64 = 0.0;
+                for _ in 0..steps {
+                    state = method.step(state, t, dt, acceleration);
+                    t += dt;
+                    worst = worst.max(((energy(state) - e0) / e0).abs());
+                }
+                worst
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_fixed_step_convergence_order() {
+        let start = ParticleState::new(Vector3::new(1.0, 0.0, 0.0), Vector3::new(0.0, 1.0, 0.0));
+        let exact = Vector3::new(1f64.cos(), 1f64.sin(), 0.0);
+        for (method, order) in [
+            (FixedStep::SemiImplicitEuler, 1),
+            (FixedStep::VelocityVerlet, 2),
+            (FixedStep::Rk4, 4),
+        ] {
+            let error = |steps: usize| {
+                let end = method.integrate(start, 0.0, 1.0, 1.0 / steps as f64, spring);
+                (end.position - exact).length()
+            };
+            let ratio = error(100) / error(200);
+            let expected = 2f64.powi(order);
+            assert!(
+                (ratio / expected - 1.0).abs() < 0.1,
+                "{method:?}: error ratio {ratio}, expected {expected}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_harmonic_oscillator_energy() {
+        let start = ParticleState::new(Vector3::new(1.0, 0.0, 0.0), Vector3::new(0.0, 0.0, 0.5));
+        // A thousand periods in ten chunks.
+        let dt = TAU / 100.0;
+        for method in [FixedStep::SemiImplicitEuler, FixedStep::VelocityVerlet] {
+            let errors = energy_errors(method, start, dt, 10_000, 10, spring, spring_energy);
+            // Symplectic: the error oscillates but doesn't grow.
+            assert!(errors[9] < errors[0] * 1.01, "{method:?}: {errors:?}");
+            assert!(errors[9] < 0.1, "{method:?}: {errors:?}");
+        }
+        // RK4 is far more accurate per step but steadily loses energy.
+        let errors = energy_errors(FixedStep::Rk4, start, dt, 10_000, 10, spring, spring_energy);
+        assert!(errors[9] > errors[0] * 5.0, "{errors:?}");
+        assert!(errors[9] < 1e-4, "{errors:?}");
+    }
+
+    #[test]
+    fn test_kepler_orbit_energy() {
+        let start = periapsis();
+        let period = TAU * 2f64.powf(1.5);
+        // Fifty orbits in five chunks.
+        let steps = 10 * 2000;
+        let dt = period / 2000.0;
+        let errors = energy_errors(
+            FixedStep::VelocityVerlet,
+            start,
+            dt,
+            steps,
+            5,
+            gravity,
+            orbit_energy,
+        );
+        assert!(errors[4] < errors[0] * 1.01, "{errors:?}");
+        assert!(errors[4] < 1e-3, "{errors:?}");
+
+        // Verlet also conserves angular momentum under a central force.
+        let momentum = |s: ParticleState| s.position.cross(s.velocity);
+        let end = FixedStep::VelocityVerlet.integrate(start, 0.0, 10.0 * period, dt, gravity);
+        assert_vec_approx_eq!(momentum(end), momentum(start), epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_dormand_prince_kepler_orbit() {
+        let start = periapsis();
+        let period = TAU * 2f64.powf(1.5);
+        let mut integrator = DormandPrince::new(1e-3, 1e-10);
+        let mut state = start;
+        for orbit in 1..=10 {
+            state = integrator.integrate(
+                state,
+                (orbit - 1) as f64 * period,
+                orbit as f64 * period,
+                gravity,
+            );
+            let drift = ((orbit_energy(state) - orbit_energy(start)) / orbit_energy(start)).abs();
+            assert!(drift < 1e-8, "orbit {orbit}: energy drift {drift}");
+        }
+        // Back at periapsis after whole orbits.
+        assert_vec_approx_eq!(state.position, start.position, epsilon = 1e-6);
+        assert_vec_approx_eq!(state.velocity, start.velocity, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn test_dormand_prince_adapts_step() {
+        let start = ParticleState::new(Vector3::new(1.0, 0.0, 0.0), Vector3::ZERO);
+        let exact = |t: f64| t.cos();
+        for tolerance in [1e-4, 1e-8, 1e-12] {
+            let mut integrator = DormandPrince::new(1.0, tolerance);
+            let end = integrator.integrate(start, 0.0, 10.0, spring);
+            assert!((end.position.x - exact(10.0)).abs() < tolerance * 1e3);
+        }
+
+        // The longest steps near the close approach of an eccentric orbit
+        // are much shorter than those far from it.
+        let start = ParticleState::new(
+            Vector3::new(0.1, 0.0, 0.0),
+            Vector3::new(0.0, 19f64.sqrt(), 0.0),
+        );
+        let mut integrator = DormandPrince::new(1e-3, 1e-9);
+        let (mut t, mut state) = (0.0, start);
+        let (mut near, mut far) = (0.0f64, 0.0f64);
+        while t < 10.0 {
+            let (dt, next) = integrator.step(state, t, gravity);
+            let r = next.position.length();
+            if r < 0.2 {
+                near = near.max(dt);
+            } else if r > 1.5 {
+                far = far.max(dt);
+            }
+            t += dt;
+            state = next;
+        }
+        assert!(far > 10.0 * near, "near {near}, far {far}");
+        assert_eq!(integrator.min_step, 1e-15);
+    }
+
+    #[test]
+    #[should_panic(expected = "needs a positive, finite max_dt")]
+    fn test_fixed_step_zero_max_dt() {
+        let _ = FixedStep::Rk4.integrate(ParticleState::default(), 0.0, 1.0, 0.0, spring);
+    }
+
+    #[test]
+    #[should_panic(expected = "needs a positive, finite max_dt")]
+    fn test_fixed_step_nan_max_dt() {
+        let _ = FixedStep::Rk4.integrate(ParticleState::default(), 0.0, 1.0, f64::NAN, spring);
+    }
+
+    #[test]
+    #[should_panic(expected = "FixedStep::integrate needs t1 >= t0")]
+    fn test_fixed_step_backwards() {
+        let _ =
+            FixedStep::VelocityVerlet.integrate(ParticleState::default(), 1.0, 0.0, 0.1, spring);
+    }
+
+    #[test]
+    #[should_panic(expected = "needs a positive, finite initial_step")]
+    fn test_dormand_prince_zero_initial_step() {
+        let _ = DormandPrince::new(0.0, 1e-6);
+    }
+
+    #[test]
+    #[should_panic(expected = "DormandPrince::integrate needs t1 >= t0")]
+    fn test_dormand_prince_backwards() {
+        let _ = DormandPrince::new(0.1, 1e-6).integrate(ParticleState::default(), 1.0, 0.0, spring);
+    }
+}
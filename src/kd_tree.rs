@@ -0,0 +1,373 @@
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+use crate::{Axis, Vector3};
+
+/// A k-d tree over a fixed set of points, each carrying a payload.
+///
+/// The tree is stored implicitly: the median of every range is its node,
+/// and the halves on either side are its subtrees. Queries identify points
+/// by their index in the slice passed to the constructor.
+#[derive(Debug, Clone)]
+pub struct KdTree<P = ()> {
+    /// Points in tree order.
+    points: Vec<Vector3>,
+    /// The original index of each point in tree order.
+    indices: Vec<usize>,
+    /// The splitting axis of each node.
+    axes: Vec<Axis>,
+    /// Payloads in original order.
+    payloads: Vec<P>,
+}
+
+/// A point found by a `KdTree` query.
+#[derive(Debug, PartialEq)]
+pub struct Neighbor<'a, P> {
+    /// Index of the point in the slice the tree was built from.
+    pub index: usize,
+    pub point: Vector3,
+    /// `(point - query).length()`.
+    pub distance: f64,
+    pub payload: &'a P,
+}
+
+impl<P> Clone for Neighbor<'_, P> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<P> Copy for Neighbor<'_, P> {}
+
+impl KdTree {
+    pub fn new(points: &[Vector3]) -> Self {
+        Self::with_payloads(points, vec![(); points.len()])
+    }
+}
+
+impl<P> KdTree<P> {
+    /// Builds a tree where `payloads[i]` belongs to `points[i]`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the lengths differ.
+    pub fn with_payloads(points: &[Vector3], payloads: Vec<P>) -> Self {
+        assert_eq!(
+            points.len(),
+            payloads.len(),
+            "KdTree::with_payloads needs one payload per point"
+        );
+        let mut entries: Vec<(Vector3, usize)> = points.iter().copied().zip(0..).collect();
+        let mut axes = vec![Axis::X; points.len()];
+        build(&mut entries, &mut axes);
+        let (points, indices) = entries.into_iter().unzip();
+        Self {
+            points,
+            indices,
+            axes,
+            payloads,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    pub fn nearest(&self, query: Vector3) -> Option<Neighbor<'_, P>> {
+        self.nearest_approx(query, 0.0)
+    }
+
+    /// A point no further than `1 + epsilon` times the distance to the true
+    /// nearest point. Larger `epsilon` prunes more of the tree.
+    pub fn nearest_approx(&self, query: Vector3, epsilon: f64) -> Option<Neighbor<'_, P>> {
+        self.k_nearest_approx(query, 1, epsilon).into_iter().next()
+    }
+
+    /// The `k` nearest points, closest first. Returns fewer if the tree holds
+    /// fewer than `k` points.
+    pub fn k_nearest(&self, query: Vector3, k: usize) -> Vec<Neighbor<'_, P>> {
+        self.k_nearest_approx(query, k, 0.0)
+    }
+
+    /// Like `k_nearest`, except that the `i`th result is only guaranteed to
+    /// be within `1 + epsilon` times the distance of the true `i`th nearest.
+    pub fn k_nearest_approx(&self, query: Vector3, k: usize, epsilon: f64) -> Vec<Neighbor<'_, P>> {
+        let mut heap = BinaryHeap::with_capacity(k.min(self.len()) + 1);
+        if k > 0 {
+            let scale = (1.0 + epsilon) * (1.0 + epsilon);
+            self.search(0, self.len(), query, k, scale, &mut heap);
+        }
+        heap.into_sorted_vec()
+            .into_iter()
+            .map(|c| self.neighbor(c.slot, query))
+            .collect()
+    }
+
+    /// Every point within `radius` of `query`, inclusive, closest first.
+    pub fn within_radius(&self, query: Vector3, radius: f64) -> Vec<Neighbor<'_, P>> {
+        let mut found = Vec::new();
+        self.collect_within(0, self.len(), query, radius, &mut found);
+        found.sort_by(|a, b| a.distance.total_cmp(&b.distance));
+        found
+    }
+
+    fn neighbor(&self, slot: usize, query: Vector3) -> Neighbor<'_, P> {
+        let index = self.indices[slot];
+        Neighbor {
+            index,
+            point: self.points[slot],
+            distance: (self.points[slot] - query).length(),
+            payload: &self.payloads[index],
+        }
+    }
+
+    /// Searches the subtree over `start..end`, keeping the best `k`
+    /// candidates in a max-heap. Distances are compared squared; `scale` is
+    /// the squared approximation factor.
+    fn search(
+        &self,
+        start: usize,
+        end: usize,
+        query: Vector3,
+        k: usize,
+        scale: f64,
+        heap: &mut BinaryHeap<Candidate>,
+    ) {
+        if start >= end {
+            return;
+        }
+        let mid = start + (end - start) / 2;
+        let point = self.points[mid];
+        let squared_distance = (point - query).dot(point - query);
+        if heap.len() < k {
+            heap.push(Candidate::new(squared_distance, mid));
+        } else if heap
+            .peek()
+            .is_some_and(|worst| squared_distance < worst.squared_distance)
+        {
+            heap.pop();
+            heap.push(Candidate::new(squared_distance, mid));
+        }
+
+        let axis = self.axes[mid];
+        let offset = query[axis] - point[axis];
+        let (near, far) = if offset < 0.0 {
+            ((start, mid), (mid + 1, end))
+        } else {
+            ((mid + 1, end), (start, mid))
+        };
+        self.search(near.0, near.1, query, k, scale, heap);
+        let reachable = heap.len() < k
+            || heap
+                .peek()
+                .is_some_and(|worst| offset * offset * scale < worst.squared_distance);
+        if reachable {
+            self.search(far.0, far.1, query, k, scale, heap);
+        }
+    }
+
+    fn collect_within<'a>(
+        &'a self,
+        start: usize,
+        end: usize,
+        query: Vector3,
+        radius: f64,
+        found: &mut Vec<Neighbor<'a, P>>,
+    ) {
+        if start >= end {
+            return;
+        }
+        let mid = start + (end - start) / 2;
+        let neighbor = self.neighbor(mid, query);
+        if neighbor.distance <= radius {
+            found.push(neighbor);
+        }
+        let offset = query[self.axes[mid]] - neighbor.point[self.axes[mid]];
+        if offset <= radius {
+            self.collect_within(start, mid, query, radius, found);
+        }
+        if -offset <= radius {
+            self.collect_within(mid + 1, end, query, radius, found);
+        }
+    }
+}
+
+/// Places the median along the axis of widest spread in the middle of
+/// `entries`, then recurses into both halves.
+fn build(entries: &mut [(Vector3, usize)], axes: &mut [Axis]) {
+    if entries.is_empty() {
+        return;
+    }
+    let (low, high) = entries
+        .iter()
+        .fold((entries[0].0, entries[0].0), |(low, high), &(p, _)| {
+            (low.min(p), high.max(p))
+        });
+    let spread = high - low;
+    let axis = if spread.x >= spread.y && spread.x >= spread.z {
+        Axis::X
+    } else if spread.y >= spread.z {
+        Axis::Y
+    } else {
+        Axis::Z
+    };
+    let mid = entries.len() / 2;
+    entries.select_nth_unstable_by(mid, |a, b| a.0[axis].total_cmp(&b.0[axis]));
+    axes[mid] = axis;
+    let (left, right) = entries.split_at_mut(mid);
+    let (left_axes, right_axes) = axes.split_at_mut(mid);
+    build(left, left_axes);
+    build(&mut right[1..], &mut right_axes[1..]);
+}
+
+/// A heap entry ordered by distance.
+struct Candidate {
+    squared_distance: f64,
+    slot: usize,
+}
+
+impl Candidate {
+    fn new(squared_distance: f64, slot: usize) -> Self {
+        Self {
+            squared_distance,
+            slot,
+        }
+    }
+}
+
+impl PartialEq for Candidate {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Candidate {}
+
+impl PartialOrd for Candidate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Candidate {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.squared_distance.total_cmp(&other.squared_distance)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::Rng;
+
+    fn random_points(rng: &mut Rng, count: usize) -> Vec<Vector3> {
+        (0..count).map(|_| rng.vector(-10.0, 10.0)).collect()
+    }
+
+    fn brute_force_distances(points: &[Vector3], query: Vector3) -> Vec<f64> {
+        let mut distances: Vec<f64> = points.iter().map(|&p| (p - query).length()).collect();
+        distances.sort_by(f64::total_cmp);
+        distances
+    }
+
+    fn distances(neighbors: &[Neighbor<'_, ()>]) -> Vec<f64> {
+        neighbors.iter().map(|n| n.distance).collect()
+    }
+
+    #[test]
+    fn test_kd_tree_matches_brute_force() {
+        let mut rng = Rng::new(16);
+        let points = random_points(&mut rng, 2000);
+        let tree = KdTree::new(&points);
+        assert_eq!(tree.len(), 2000);
+        for _ in 0..200 {
+            let query = rng.vector(-12.0, 12.0);
+            let expected = brute_force_distances(&points, query);
+
+            let nearest = tree.nearest(query).unwrap();
+            assert_eq!(nearest.distance, expected[0]);
+            assert_eq!(points[nearest.index], nearest.point);
+
+            let k_nearest = tree.k_nearest(query, 10);
+            assert_eq!(distances(&k_nearest), expected[..10]);
+            for n in &k_nearest {
+                assert_eq!(points[n.index], n.point);
+            }
+
+            let radius = rng.range(0.0, 3.0);
+            let mut found: Vec<usize> = tree
+                .within_radius(query, radius)
+                .iter()
+                .map(|n| n.index)
+                .collect();
+            found.sort_unstable();
+            let expected: Vec<usize> = (0..points.len())
+                .filter(|&i| (points[i] - query).length() <= radius)
+                .collect();
+            assert_eq!(found, expected);
+        }
+    }
+
+    #[test]
+    fn test_kd_tree_approximate() {
+        let mut rng = Rng::new(17);
+        let points = random_points(&mut rng, 2000);
+        let tree = KdTree::new(&points);
+        let epsilon = 0.5;
+        for _ in 0..200 {
+            let query = rng.vector(-12.0, 12.0);
+            let expected = brute_force_distances(&points, query);
+            let nearest = tree.nearest_approx(query, epsilon).unwrap();
+            assert!(nearest.distance <= (1.0 + epsilon) * expected[0]);
+            let k_nearest = tree.k_nearest_approx(query, 5, epsilon);
+            assert_eq!(k_nearest.len(), 5);
+            for (found, exact) in k_nearest.iter().zip(&expected) {
+                assert!(found.distance <= (1.0 + epsilon) * exact);
+            }
+        }
+    }
+
+    #[test]
+    fn test_kd_tree_payloads() {
+        let points = [
+            Vector3::new(0.0, 0.0, 0.0),
+            Vector3::new(5.0, 0.0, 0.0),
+            Vector3::new(0.0, 5.0, 0.0),
+        ];
+        let tree = KdTree::with_payloads(&points, vec!["origin", "x", "y"]);
+        let nearest = tree.nearest(Vector3::new(4.0, 1.0, 0.0)).unwrap();
+        assert_eq!((nearest.index, *nearest.payload), (1, "x"));
+        let names: Vec<&str> = tree
+            .k_nearest(Vector3::new(0.0, 4.0, 0.0), 2)
+            .iter()
+            .map(|n| *n.payload)
+            .collect();
+        assert_eq!(names, ["y", "origin"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "one payload per point")]
+    fn test_kd_tree_payload_mismatch() {
+        KdTree::with_payloads(&[Vector3::ZERO], vec![1, 2]);
+    }
+
+    #[test]
+    fn test_kd_tree_edge_cases() {
+        let empty = KdTree::new(&[]);
+        assert!(empty.is_empty());
+        assert_eq!(empty.nearest(Vector3::ZERO), None);
+        assert!(empty.k_nearest(Vector3::ZERO, 3).is_empty());
+        assert!(empty.within_radius(Vector3::ZERO, 1.0).is_empty());
+
+        let points = vec![Vector3::ONE; 50];
+        let tree = KdTree::new(&points);
+        assert_eq!(tree.k_nearest(Vector3::ZERO, 0).len(), 0);
+        assert_eq!(tree.k_nearest(Vector3::ZERO, 80).len(), 50);
+        assert_eq!(tree.k_nearest(Vector3::ZERO, usize::MAX).len(), 50);
+        assert_eq!(tree.within_radius(Vector3::ONE, 0.0).len(), 50);
+    }
+}
@@ -1,135 +1,486 @@
-use std::ops::{Add, Div, Mul, Sub};
+#[macro_use]
+mod macros;
+#[macro_use]
+mod approx;
+mod aabb;
+mod batch;
+mod bvh;
+#[cfg(feature = "bytemuck")]
+mod bytemuck;
+mod convex_hull;
+mod coordinates;
+mod curve;
+mod error;
+mod frame;
+mod geodesy;
+mod integrator;
+mod kd_tree;
+mod matrix3;
+mod matrix4;
+mod plane;
+mod point3;
+pub mod predicates;
+mod quaternion;
+mod ray;
+mod rigid_body;
+mod scalar;
+#[cfg(feature = "serde")]
+pub mod serde;
+#[cfg(all(feature = "simd", target_arch = "x86_64"))]
+mod simd;
+mod sphere;
+#[cfg(test)]
+mod test_util;
+mod triangle;
+mod unit_vector3;
+mod vector2;
+mod vector4;
+
+pub use aabb::Aabb;
+pub use approx::ApproxEq;
+#[doc(hidden)]
+pub use approx::{__default_epsilon, __default_max_relative};
+pub use batch::{Mask, Vector3Batch, Vector3x4, Vector3x8};
+pub use bvh::{Bounded, Bvh, ClosestPoint, RayIntersect};
+pub use convex_hull::{ConvexHull, HullFace};
+pub use coordinates::{
+    Cylindrical, MathConvention, PhysicsConvention, Spherical, SphericalConvention,
+};
+pub use curve::{
+    ArcLengthTable, CatmullRom, CatmullRomParameterization, CubicBezier, CubicHermite, Curve,
+    CurveFrame, QuadraticBezier,
+};
+pub use error::GeometryError;
+pub use frame::{FramePoint3, FrameTransform, FrameVector3};
+pub use geodesy::{Ellipsoid, Lla, LocalTangentFrame};
+pub use integrator::{DormandPrince, FixedStep, ParticleState};
+pub use kd_tree::{KdTree, Neighbor};
+pub use matrix3::Matrix3;
+pub use matrix4::Matrix4;
+pub use plane::Plane;
+pub use point3::Point3;
+pub use quaternion::Quaternion;
+pub use ray::{Ray, RayHit};
+pub use rigid_body::RigidBody;
+pub use scalar::{Float, Scalar};
+pub use sphere::Sphere;
+pub use triangle::Triangle;
+pub use unit_vector3::UnitVector3;
+pub use vector2::{Vec2d, Vec2f, Vec2i, Vector2};
+pub use vector4::{Vec4d, Vec4f, Vec4i, Vector4};
+
+use std::iter::{Product, Sum};
+use std::ops::{
+    Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Rem, Sub, SubAssign,
+};
 
 #[derive(Debug, Clone, Copy, PartialEq)]
-pub struct Vector3 {
-    pub x: f64,
-    pub y: f64,
-    pub z: f64,
+#[repr(C)]
+pub struct Vector3<T = f64> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
 }
 
-impl Vector3 {
+pub type Vec3f = Vector3<f32>;
+pub type Vec3d = Vector3<f64>;
+pub type Vec3i = Vector3<i32>;
+
+/// A coordinate axis, usable as an index into a `Vector3`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Axis {
+    X,
+    Y,
+    Z,
+}
+
+impl<T: Scalar> Vector3<T> {
     pub const ZERO: Self = Self {
-        x: 0.0,
-        y: 0.0,
-        z: 0.0,
+        x: T::ZERO,
+        y: T::ZERO,
+        z: T::ZERO,
     };
 
     pub const ONE: Self = Self {
-        x: 1.0,
-        y: 1.0,
-        z: 1.0,
+        x: T::ONE,
+        y: T::ONE,
+        z: T::ONE,
     };
 
-    pub fn new(x: f64, y: f64, z: f64) -> Self {
+    pub fn new(x: T, y: T, z: T) -> Self {
         Self { x, y, z }
     }
 
-    pub fn dot(self, other: Self) -> f64 {
-        self.x * other.x + self.y * other.y + self.z * other.z
+    pub fn dot(self, other: Self) -> T {
+        T::dot3(self.into(), other.into())
     }
 
     pub fn cross(self, other: Self) -> Self {
-        Self {
-            x: self.y * other.z - self.z * other.y,
-            y: self.z * other.x - self.x * other.z,
-            z: self.x * other.y - self.y * other.x,
-        }
+        T::cross3(self.into(), other.into()).into()
+    }
+
+    pub fn extend(self, w: T) -> Vector4<T> {
+        Vector4::new(self.x, self.y, self.z, w)
+    }
+
+    pub fn truncate(self) -> Vector2<T> {
+        Vector2::new(self.x, self.y)
+    }
+
+    /// Component-wise minimum.
+    pub fn min(self, other: Self) -> Self {
+        let min = |a: T, b: T| if b < a { b } else { a };
+        Self::new(
+            min(self.x, other.x),
+            min(self.y, other.y),
+            min(self.z, other.z),
+        )
+    }
+
+    /// Component-wise maximum.
+    pub fn max(self, other: Self) -> Self {
+        let max = |a: T, b: T| if b > a { b } else { a };
+        Self::new(
+            max(self.x, other.x),
+            max(self.y, other.y),
+            max(self.z, other.z),
+        )
     }
+}
 
-    pub fn length(self) -> f64 {
+impl<T: Float> Vector3<T> {
+    pub fn length(self) -> T {
         self.dot(self).sqrt()
     }
 
+    pub fn is_finite(self) -> bool {
+        self.x.is_finite() && self.y.is_finite() && self.z.is_finite()
+    }
+
+    /// Divides by the length without any checks, so `ZERO` yields NaN
+    /// components. See `try_normalized` for a checked version.
     pub fn normalized(self) -> Self {
         self / self.length()
     }
+
+    pub fn try_normalized(self) -> Result<Self, GeometryError> {
+        self.normalize_with_epsilon(T::ZERO)
+    }
+
+    /// Returns `fallback` if the vector can't be normalized.
+    pub fn normalized_or(self, fallback: Self) -> Self {
+        self.try_normalized().unwrap_or(fallback)
+    }
+
+    /// Normalizes the vector, treating lengths not greater than `epsilon` as
+    /// degenerate.
+    pub fn normalize_with_epsilon(self, epsilon: T) -> Result<Self, GeometryError> {
+        if !self.is_finite() {
+            return Err(GeometryError::NonFiniteComponent);
+        }
+        // Scaling by the largest component first keeps the squared length
+        // from overflowing or underflowing.
+        let [x, y, z] = [self.x.abs(), self.y.abs(), self.z.abs()];
+        let largest = if x > y { x } else { y };
+        let largest = if largest > z { largest } else { z };
+        if largest == T::ZERO {
+            return Err(GeometryError::DegenerateVector);
+        }
+        let scaled = self / largest;
+        let length = scaled.length();
+        if length * largest <= epsilon {
+            return Err(GeometryError::DegenerateVector);
+        }
+        Ok(scaled / length)
+    }
+
+    /// Linear interpolation: `self` at `t = 0`, `other` at `t = 1`.
+    /// `t` outside `[0, 1]` extrapolates.
+    pub fn lerp(self, other: Self, t: T) -> Self {
+        self + (other - self) * t
+    }
 }
 
-impl Add for Vector3 {
+impl Vector3 {
+    /// Spherical linear interpolation for directions: rotates from `self`
+    /// towards `other` at a constant angular rate while interpolating the
+    /// length linearly, so unit inputs give unit outputs.
+    ///
+    /// Falls back to `lerp` if either vector is zero or they are parallel.
+    /// For opposite vectors the plane of rotation is arbitrary.
+    pub fn slerp(self, other: Self, t: f64) -> Self {
+        let (Ok(from), Ok(to)) = (self.try_normalized(), other.try_normalized()) else {
+            return self.lerp(other, t);
+        };
+        let d = from.dot(to);
+        let angle = from.cross(to).length().atan2(d);
+        // The unit vector perpendicular to `from` in the plane of rotation.
+        let perpendicular = match (to - from * d).try_normalized() {
+            Ok(p) => p,
+            Err(_) if d > 0.0 => return self.lerp(other, t),
+            Err(_) => from.any_perpendicular(),
+        };
+        let (sin, cos) = (t * angle).sin_cos();
+        let (from_length, to_length) = (self.length(), other.length());
+        (from * cos + perpendicular * sin) * (from_length + (to_length - from_length) * t)
+    }
+
+    /// Some unit vector perpendicular to this unit vector.
+    pub(crate) fn any_perpendicular(self) -> Self {
+        let mut p = Vector3::new(1.0, 0.0, 0.0).cross(self);
+        if p.dot(p) < 1e-12 {
+            p = Vector3::new(0.0, 1.0, 0.0).cross(self);
+        }
+        p.normalized()
+    }
+}
+
+impl<T: Scalar> Add for Vector3<T> {
     type Output = Self;
 
     fn add(self, other: Self) -> Self {
-        Self {
-            x: self.x + other.x,
-            y: self.y + other.y,
-            z: self.z + other.z,
-        }
+        T::add3(self.into(), other.into()).into()
     }
 }
 
-impl Sub for Vector3 {
+impl<T: Scalar> Sub for Vector3<T> {
     type Output = Self;
 
     fn sub(self, other: Self) -> Self {
-        Self {
-            x: self.x - other.x,
-            y: self.y - other.y,
-            z: self.z - other.z,
-        }
+        T::sub3(self.into(), other.into()).into()
+    }
+}
+
+impl<T: Scalar> Mul<T> for Vector3<T> {
+    type Output = Self;
+
+    fn mul(self, other: T) -> Self {
+        T::mul3(self.into(), [other; 3]).into()
     }
 }
 
-impl Mul<f64> for Vector3 {
+impl<T: Scalar> Mul for Vector3<T> {
     type Output = Self;
 
-    fn mul(self, other: f64) -> Self {
+    fn mul(self, other: Self) -> Self {
+        T::mul3(self.into(), other.into()).into()
+    }
+}
+
+impl<T: Float> Div<T> for Vector3<T> {
+    type Output = Self;
+
+    fn div(self, other: T) -> Self {
+        T::div3(self.into(), [other; 3]).into()
+    }
+}
+
+impl<T: Float> Div for Vector3<T> {
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self {
+        T::mul3(self.into(), T::div3([T::ONE; 3], other.into())).into()
+    }
+}
+
+impl_scalar_lhs_mul!(Vector3 { x, y, z }; f32, f64, i8, i16, i32, i64, i128, isize);
+impl_scalar_lhs_div!(Vector3 { x, y, z }; f32, f64);
+
+impl<T: Scalar> Rem<T> for Vector3<T> {
+    type Output = Self;
+
+    fn rem(self, other: T) -> Self {
         Self {
-            x: self.x * other,
-            y: self.y * other,
-            z: self.z * other,
+            x: self.x % other,
+            y: self.y % other,
+            z: self.z % other,
         }
     }
 }
 
-impl Mul<Vector3> for f64 {
-    type Output = Vector3;
+impl<T: Scalar> Rem for Vector3<T> {
+    type Output = Self;
 
-    fn mul(self, other: Vector3) -> Vector3 {
-        other * self
+    fn rem(self, other: Self) -> Self {
+        Self {
+            x: self.x % other.x,
+            y: self.y % other.y,
+            z: self.z % other.z,
+        }
     }
 }
 
-impl Mul for Vector3 {
+impl<T: Scalar> Neg for Vector3<T> {
     type Output = Self;
 
-    fn mul(self, other: Self) -> Self {
+    fn neg(self) -> Self {
         Self {
-            x: self.x * other.x,
-            y: self.y * other.y,
-            z: self.z * other.z,
+            x: -self.x,
+            y: -self.y,
+            z: -self.z,
         }
     }
 }
 
-impl Div<f64> for Vector3 {
-    type Output = Self;
+impl<T: Scalar> Neg for &Vector3<T> {
+    type Output = Vector3<T>;
 
-    fn div(self, other: f64) -> Self {
-        Vector3 {
-            x: self.x / other,
-            y: self.y / other,
-            z: self.z / other,
+    fn neg(self) -> Vector3<T> {
+        -*self
+    }
+}
+
+forward_ref_binop!(impl<T: Scalar> Add, add for Vector3<T>, Vector3<T>);
+forward_ref_binop!(impl<T: Scalar> Sub, sub for Vector3<T>, Vector3<T>);
+forward_ref_binop!(impl<T: Scalar> Mul, mul for Vector3<T>, Vector3<T>);
+forward_ref_binop!(impl<T: Scalar> Mul, mul for Vector3<T>, T);
+forward_ref_binop!(impl<T: Float> Div, div for Vector3<T>, Vector3<T>);
+forward_ref_binop!(impl<T: Float> Div, div for Vector3<T>, T);
+forward_ref_binop!(impl<T: Scalar> Rem, rem for Vector3<T>, Vector3<T>);
+forward_ref_binop!(impl<T: Scalar> Rem, rem for Vector3<T>, T);
+
+impl<T: Scalar> AddAssign for Vector3<T> {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl<T: Scalar> SubAssign for Vector3<T> {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl<T: Scalar> MulAssign<T> for Vector3<T> {
+    fn mul_assign(&mut self, other: T) {
+        *self = *self * other;
+    }
+}
+
+impl<T: Scalar> MulAssign for Vector3<T> {
+    fn mul_assign(&mut self, other: Self) {
+        *self = *self * other;
+    }
+}
+
+impl<T: Float> DivAssign<T> for Vector3<T> {
+    fn div_assign(&mut self, other: T) {
+        *self = *self / other;
+    }
+}
+
+impl<T: Float> DivAssign for Vector3<T> {
+    fn div_assign(&mut self, other: Self) {
+        *self = *self / other;
+    }
+}
+
+impl<T> Index<Axis> for Vector3<T> {
+    type Output = T;
+
+    fn index(&self, axis: Axis) -> &T {
+        match axis {
+            Axis::X => &self.x,
+            Axis::Y => &self.y,
+            Axis::Z => &self.z,
         }
     }
 }
 
-impl Div<Vector3> for f64 {
-    type Output = Vector3;
+impl<T> IndexMut<Axis> for Vector3<T> {
+    fn index_mut(&mut self, axis: Axis) -> &mut T {
+        match axis {
+            Axis::X => &mut self.x,
+            Axis::Y => &mut self.y,
+            Axis::Z => &mut self.z,
+        }
+    }
+}
+
+impl<T> Index<usize> for Vector3<T> {
+    type Output = T;
 
-    fn div(self, other: Vector3) -> Vector3 {
-        Vector3 {
-            x: self / other.x,
-            y: self / other.y,
-            z: self / other.z,
+    fn index(&self, index: usize) -> &T {
+        match index {
+            0 => &self.x,
+            1 => &self.y,
+            2 => &self.z,
+            _ => panic!("index out of bounds: Vector3 has 3 components but the index is {index}"),
         }
     }
 }
 
-impl Div for Vector3 {
-    type Output = Self;
+impl<T> IndexMut<usize> for Vector3<T> {
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        match index {
+            0 => &mut self.x,
+            1 => &mut self.y,
+            2 => &mut self.z,
+            _ => panic!("index out of bounds: Vector3 has 3 components but the index is {index}"),
+        }
+    }
+}
 
-    fn div(self, other: Self) -> Self {
-        self * (1.0 / other)
+impl<T: Scalar> Sum for Vector3<T> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, Add::add)
+    }
+}
+
+impl<'a, T: Scalar> Sum<&'a Vector3<T>> for Vector3<T> {
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
+impl<T: Scalar> Product for Vector3<T> {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ONE, Mul::mul)
+    }
+}
+
+impl<'a, T: Scalar> Product<&'a Vector3<T>> for Vector3<T> {
+    fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.copied().product()
+    }
+}
+
+impl<T: Scalar> Default for Vector3<T> {
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl<T> From<[T; 3]> for Vector3<T> {
+    fn from([x, y, z]: [T; 3]) -> Self {
+        Self { x, y, z }
+    }
+}
+
+impl<T> From<(T, T, T)> for Vector3<T> {
+    fn from((x, y, z): (T, T, T)) -> Self {
+        Self { x, y, z }
+    }
+}
+
+impl<T> From<Vector3<T>> for [T; 3] {
+    fn from(v: Vector3<T>) -> Self {
+        [v.x, v.y, v.z]
+    }
+}
+
+impl<T> From<Vector3<T>> for (T, T, T) {
+    fn from(v: Vector3<T>) -> Self {
+        (v.x, v.y, v.z)
+    }
+}
+
+impl<T> IntoIterator for Vector3<T> {
+    type Item = T;
+    type IntoIter = std::array::IntoIter<T, 3>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        <[T; 3]>::from(self).into_iter()
     }
 }
 
@@ -197,10 +548,8 @@ mod tests {
             z: 0.0,
         };
         let normalized = v.normalized();
-        assert_eq!(normalized.x, 0.6);
-        assert_eq!(normalized.y, 0.8);
-        assert_eq!(normalized.z, 0.0);
-        assert_eq!(normalized.length(), 1.0);
+        assert_vec_approx_eq!(normalized, Vector3::new(0.6, 0.8, 0.0));
+        assert_vec_approx_eq!(normalized.length(), 1.0);
     }
 
     #[test]
@@ -246,7 +595,7 @@ mod tests {
             y: 2.0,
             z: 3.0,
         };
-        let scalar = 2.0;
+        let scalar: f64 = 2.0;
 
         // Vector * scalar
         let result1 = v * scalar;
@@ -274,7 +623,7 @@ mod tests {
             y: 4.0,
             z: 6.0,
         };
-        let scalar = 2.0;
+        let scalar: f64 = 2.0;
 
         // Vector / scalar
         let result1 = v / scalar;
@@ -294,4 +643,255 @@ mod tests {
         assert_eq!(result3.y, 1.0);
         assert_eq!(result3.z, 1.0);
     }
+
+    #[test]
+    fn test_vector_f32() {
+        let v = Vec3f::new(3.0, 4.0, 0.0);
+        assert_eq!(v.length(), 5.0);
+        assert_eq!(2.0f32 * v, Vec3f::new(6.0, 8.0, 0.0));
+        assert_eq!(v / 2.0, Vec3f::new(1.5, 2.0, 0.0));
+    }
+
+    #[test]
+    fn test_vector_integer() {
+        let v1 = Vec3i::new(1, 2, 3);
+        let v2 = Vec3i::new(4, 5, 6);
+        assert_eq!(v1.dot(v2), 32);
+        assert_eq!(v1.cross(v2), Vec3i::new(-3, 6, -3));
+        assert_eq!(v1 + v2, Vec3i::new(5, 7, 9));
+        assert_eq!(v2 - v1, Vec3i::new(3, 3, 3));
+        assert_eq!(2 * v1, Vec3i::new(2, 4, 6));
+        assert_eq!(v1 * v2, Vec3i::new(4, 10, 18));
+        assert_eq!(Vec3i::ZERO + Vec3i::ONE, Vec3i::new(1, 1, 1));
+    }
+
+    #[test]
+    fn test_vector_try_normalized() {
+        let v = Vector3::new(0.0, 3.0, 4.0);
+        assert_eq!(v.try_normalized(), Ok(Vector3::new(0.0, 0.6, 0.8)));
+        assert_eq!(
+            Vec3d::ZERO.try_normalized(),
+            Err(GeometryError::DegenerateVector)
+        );
+        assert_eq!(
+            Vector3::new(1.0, f64::NAN, 0.0).try_normalized(),
+            Err(GeometryError::NonFiniteComponent)
+        );
+        assert_eq!(
+            Vector3::new(f64::INFINITY, 0.0, 0.0).try_normalized(),
+            Err(GeometryError::NonFiniteComponent)
+        );
+        // The squared lengths underflow to zero and overflow to infinity.
+        assert_eq!(
+            Vector3::new(1e-200, 0.0, 0.0).try_normalized(),
+            Ok(Vector3::new(1.0, 0.0, 0.0))
+        );
+        assert_vec_approx_eq!(
+            Vector3::new(1e200, 1e200, 1e200).try_normalized().unwrap(),
+            Vector3::ONE / 3f64.sqrt()
+        );
+        assert_vec_approx_eq!(
+            Vector3::new(-3e300, 4e300, 0.0).try_normalized().unwrap(),
+            Vector3::new(-0.6, 0.8, 0.0)
+        );
+    }
+
+    #[test]
+    fn test_vector_normalized_or() {
+        let fallback = Vector3::new(0.0, 0.0, 1.0);
+        assert_eq!(Vector3::ZERO.normalized_or(fallback), fallback);
+        assert_eq!(
+            Vector3::new(2.0, 0.0, 0.0).normalized_or(fallback),
+            Vector3::new(1.0, 0.0, 0.0)
+        );
+    }
+
+    #[test]
+    fn test_vector_normalize_with_epsilon() {
+        let v = Vector3::new(1e-3, 0.0, 0.0);
+        assert_eq!(
+            v.normalize_with_epsilon(1e-2),
+            Err(GeometryError::DegenerateVector)
+        );
+        assert_eq!(
+            v.normalize_with_epsilon(1e-4),
+            Ok(Vector3::new(1.0, 0.0, 0.0))
+        );
+        assert_eq!(
+            Vec3f::ZERO.normalize_with_epsilon(0.0),
+            Err(GeometryError::DegenerateVector)
+        );
+    }
+
+    #[test]
+    fn test_vector_assign_ops() {
+        let mut v = Vector3::new(1.0, 2.0, 3.0);
+        v += Vector3::new(1.0, 1.0, 1.0);
+        assert_eq!(v, Vector3::new(2.0, 3.0, 4.0));
+        v -= Vector3::new(0.0, 1.0, 2.0);
+        assert_eq!(v, Vector3::new(2.0, 2.0, 2.0));
+        v *= 3.0;
+        assert_eq!(v, Vector3::new(6.0, 6.0, 6.0));
+        v *= Vector3::new(1.0, 2.0, 3.0);
+        assert_eq!(v, Vector3::new(6.0, 12.0, 18.0));
+        v /= 2.0;
+        assert_eq!(v, Vector3::new(3.0, 6.0, 9.0));
+        v /= Vector3::new(3.0, 2.0, 1.0);
+        assert_eq!(v, Vector3::new(1.0, 3.0, 9.0));
+
+        let mut i = Vec3i::new(1, 2, 3);
+        i += Vec3i::ONE;
+        i *= 2;
+        assert_eq!(i, Vec3i::new(4, 6, 8));
+    }
+
+    #[test]
+    fn test_vector_neg_rem() {
+        let v = Vector3::new(1.0, -2.0, 3.0);
+        assert_eq!(-v, Vector3::new(-1.0, 2.0, -3.0));
+        assert_eq!(-&v, Vector3::new(-1.0, 2.0, -3.0));
+        assert_eq!(
+            Vector3::new(5.5, -7.0, 9.0) % 2.0,
+            Vector3::new(1.5, -1.0, 1.0)
+        );
+        assert_eq!(
+            Vec3i::new(7, 8, -9) % Vec3i::new(2, 3, 4),
+            Vec3i::new(1, 2, -1)
+        );
+    }
+
+    #[test]
+    #[allow(clippy::op_ref)]
+    fn test_vector_ref_ops() {
+        let a = Vector3::new(1.0, 2.0, 3.0);
+        let b = Vector3::new(4.0, 5.0, 6.0);
+        assert_eq!(&a + &b, a + b);
+        assert_eq!(&a - b, a - b);
+        assert_eq!(a * &b, a * b);
+        assert_eq!(&a / &b, a / b);
+        assert_eq!(&a * 2.0, a * 2.0);
+        assert_eq!(&a / &2.0, a / 2.0);
+        assert_eq!(&a % 2.0, a % 2.0);
+    }
+
+    #[test]
+    fn test_vector_index() {
+        let mut v = Vector3::new(1.0, 2.0, 3.0);
+        assert_eq!(v[0], 1.0);
+        assert_eq!(v[1], 2.0);
+        assert_eq!(v[Axis::Z], 3.0);
+        v[1] = 5.0;
+        v[Axis::X] = 4.0;
+        assert_eq!(v, Vector3::new(4.0, 5.0, 3.0));
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds")]
+    fn test_vector_index_out_of_bounds() {
+        let v = Vector3::new(1.0, 2.0, 3.0);
+        let _ = v[3];
+    }
+
+    #[test]
+    fn test_vector_sum_product() {
+        let vs = [
+            Vector3::new(1.0, 2.0, 3.0),
+            Vector3::new(4.0, 5.0, 6.0),
+            Vector3::new(-1.0, 1.0, 0.5),
+        ];
+        assert_eq!(vs.iter().sum::<Vec3d>(), Vector3::new(4.0, 8.0, 9.5));
+        assert_eq!(vs.into_iter().sum::<Vec3d>(), Vector3::new(4.0, 8.0, 9.5));
+        assert_eq!(vs.iter().product::<Vec3d>(), Vector3::new(-4.0, 10.0, 9.0));
+        assert_eq!(
+            vs.into_iter().product::<Vec3d>(),
+            Vector3::new(-4.0, 10.0, 9.0)
+        );
+        assert_eq!(std::iter::empty::<Vec3i>().sum::<Vec3i>(), Vec3i::ZERO);
+        assert_eq!(std::iter::empty::<Vec3i>().product::<Vec3i>(), Vec3i::ONE);
+    }
+
+    #[test]
+    fn test_vector_conversions() {
+        let v = Vector3::from([1.0, 2.0, 3.0]);
+        assert_eq!(v, Vector3::new(1.0, 2.0, 3.0));
+        assert_eq!(Vector3::from((1.0, 2.0, 3.0)), v);
+        let array: [f64; 3] = v.into();
+        assert_eq!(array, [1.0, 2.0, 3.0]);
+        let tuple: (f64, f64, f64) = v.into();
+        assert_eq!(tuple, (1.0, 2.0, 3.0));
+        assert_eq!(Vec3d::default(), Vector3::ZERO);
+        assert_eq!(v.into_iter().collect::<Vec<_>>(), vec![1.0, 2.0, 3.0]);
+        assert_eq!(v.into_iter().fold(0.0, |a, b| a + b), 6.0);
+    }
+
+    #[test]
+    fn test_vector_min_max() {
+        let a = Vector3::new(1.0, 5.0, -2.0);
+        let b = Vector3::new(3.0, -1.0, -2.5);
+        assert_eq!(a.min(b), Vector3::new(1.0, -1.0, -2.5));
+        assert_eq!(a.max(b), Vector3::new(3.0, 5.0, -2.0));
+        assert_eq!(Vec3i::new(1, 2, 3).max(Vec3i::ZERO), Vec3i::new(1, 2, 3));
+    }
+
+    #[test]
+    fn test_vector_layout() {
+        use std::mem::{align_of, offset_of, size_of};
+
+        assert_eq!(size_of::<Vec3d>(), 24);
+        assert_eq!(align_of::<Vec3d>(), align_of::<f64>());
+        assert_eq!(offset_of!(Vec3d, x), 0);
+        assert_eq!(offset_of!(Vec3d, y), 8);
+        assert_eq!(offset_of!(Vec3d, z), 16);
+        assert_eq!(size_of::<Vec3f>(), 12);
+        assert_eq!(offset_of!(Vec3f, y), 4);
+        assert_eq!(offset_of!(Vec3f, z), 8);
+        assert_eq!(size_of::<Vector2<f32>>(), 8);
+        assert_eq!(size_of::<Vector4<f32>>(), 16);
+        assert_eq!(size_of::<Matrix4<f32>>(), 64);
+        assert_eq!(size_of::<Quaternion>(), 32);
+        assert_eq!(offset_of!(Quaternion, w), 24);
+    }
+
+    #[test]
+    fn test_vector_lerp() {
+        let a = Vector3::new(1.0, 2.0, 3.0);
+        let b = Vector3::new(5.0, -2.0, 3.0);
+        assert_eq!(a.lerp(b, 0.0), a);
+        assert_eq!(a.lerp(b, 1.0), b);
+        assert_eq!(a.lerp(b, 0.25), Vector3::new(2.0, 1.0, 3.0));
+        assert_eq!(a.lerp(b, -1.0), Vector3::new(-3.0, 6.0, 3.0));
+        assert_eq!(Vec3f::ZERO.lerp(Vec3f::ONE, 0.5), Vec3f::new(0.5, 0.5, 0.5));
+    }
+
+    #[test]
+    fn test_vector_slerp() {
+        let x = Vector3::new(1.0, 0.0, 0.0);
+        let y = Vector3::new(0.0, 1.0, 0.0);
+        assert_eq!(x.slerp(y, 0.0), x);
+        assert_vec_approx_eq!(x.slerp(y, 1.0), y, epsilon = 1e-15);
+        let half = std::f64::consts::FRAC_1_SQRT_2;
+        assert_vec_approx_eq!(x.slerp(y, 0.5), Vector3::new(half, half, 0.0));
+        // Constant angular rate and interpolated length.
+        let v = (x * 2.0).slerp(y * 4.0, 1.0 / 3.0);
+        assert_vec_approx_eq!(v.length(), 8.0 / 3.0);
+        assert_vec_approx_eq!(v.y.atan2(v.x), std::f64::consts::FRAC_PI_6);
+
+        // Opposite directions still rotate, through some perpendicular.
+        let v = x.slerp(-x, 0.5);
+        assert_vec_approx_eq!(v.length(), 1.0);
+        assert_vec_approx_eq!(v.dot(x), 0.0, epsilon = 1e-15);
+        assert_vec_approx_eq!(x.slerp(-x, 1.0), -x, epsilon = 1e-15);
+
+        assert_eq!(x.slerp(x * 3.0, 0.5), x * 2.0);
+        assert_eq!(Vector3::ZERO.slerp(y, 0.5), y * 0.5);
+    }
+
+    #[test]
+    fn test_vector_extend_truncate() {
+        let v = Vector3::new(1.0, 2.0, 3.0);
+        assert_eq!(v.extend(4.0), Vector4::new(1.0, 2.0, 3.0, 4.0));
+        assert_eq!(v.truncate(), Vector2::new(1.0, 2.0));
+        assert_eq!(v.extend(4.0).truncate(), v);
+        assert_eq!(v.truncate().extend(3.0), v);
+    }
 }
@@ -0,0 +1,61 @@
+// Scalar-on-the-left operators can't be written generically because of the
+// orphan rules, so they are stamped out per concrete scalar type.
+macro_rules! impl_scalar_lhs_mul {
+    ($vector:ident $fields:tt; $($t:ty),*) => {
+        $(
+            impl Mul<$vector<$t>> for $t {
+                type Output = $vector<$t>;
+
+                fn mul(self, other: $vector<$t>) -> $vector<$t> {
+                    other * self
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_scalar_lhs_div {
+    ($vector:ident $fields:tt; $($t:ty),*) => {
+        $(impl_scalar_lhs_div!(@impl $vector $fields $t);)*
+    };
+    (@impl $vector:ident { $($field:ident),+ } $t:ty) => {
+        impl Div<$vector<$t>> for $t {
+            type Output = $vector<$t>;
+
+            fn div(self, other: $vector<$t>) -> $vector<$t> {
+                $vector {
+                    $($field: self / other.$field),+
+                }
+            }
+        }
+    };
+}
+
+// Implements `&a op b`, `a op &b` and `&a op &b` in terms of the by-value impl.
+macro_rules! forward_ref_binop {
+    (impl<$t:ident: $bound:ident> $imp:ident, $method:ident for $lhs:ty, $rhs:ty) => {
+        impl<$t: $bound> $imp<$rhs> for &$lhs {
+            type Output = <$lhs as $imp<$rhs>>::Output;
+
+            fn $method(self, other: $rhs) -> Self::Output {
+                $imp::$method(*self, other)
+            }
+        }
+
+        impl<$t: $bound> $imp<&$rhs> for $lhs {
+            type Output = <$lhs as $imp<$rhs>>::Output;
+
+            fn $method(self, other: &$rhs) -> Self::Output {
+                $imp::$method(self, *other)
+            }
+        }
+
+        impl<$t: $bound> $imp<&$rhs> for &$lhs {
+            type Output = <$lhs as $imp<$rhs>>::Output;
+
+            fn $method(self, other: &$rhs) -> Self::Output {
+                $imp::$method(*self, *other)
+            }
+        }
+    };
+}
@@ -0,0 +1,237 @@
+use std::ops::{Add, Mul, Sub};
+
+use crate::{Float, Scalar, Vector3};
+
+/// A 3x3 column-major matrix.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[repr(C)]
+pub struct Matrix3<T = f64> {
+    pub cols: [Vector3<T>; 3],
+}
+
+impl<T: Scalar> Matrix3<T> {
+    pub const ZERO: Self = Self {
+        cols: [Vector3::ZERO; 3],
+    };
+
+    pub const IDENTITY: Self = Self {
+        cols: [
+            Vector3 {
+                x: T::ONE,
+                y: T::ZERO,
+                z: T::ZERO,
+            },
+            Vector3 {
+                x: T::ZERO,
+                y: T::ONE,
+                z: T::ZERO,
+            },
+            Vector3 {
+                x: T::ZERO,
+                y: T::ZERO,
+                z: T::ONE,
+            },
+        ],
+    };
+
+    pub fn from_cols(x: Vector3<T>, y: Vector3<T>, z: Vector3<T>) -> Self {
+        Self { cols: [x, y, z] }
+    }
+
+    pub fn from_rows(x: Vector3<T>, y: Vector3<T>, z: Vector3<T>) -> Self {
+        Self::from_cols(x, y, z).transpose()
+    }
+
+    pub fn from_diagonal(diagonal: Vector3<T>) -> Self {
+        Self::from_cols(
+            Vector3::new(diagonal.x, T::ZERO, T::ZERO),
+            Vector3::new(T::ZERO, diagonal.y, T::ZERO),
+            Vector3::new(T::ZERO, T::ZERO, diagonal.z),
+        )
+    }
+
+    pub fn transpose(self) -> Self {
+        let [x, y, z] = self.cols;
+        Self::from_cols(
+            Vector3::new(x.x, y.x, z.x),
+            Vector3::new(x.y, y.y, z.y),
+            Vector3::new(x.z, y.z, z.z),
+        )
+    }
+
+    pub fn determinant(self) -> T {
+        let [x, y, z] = self.cols;
+        x.dot(y.cross(z))
+    }
+}
+
+impl<T: Float> Matrix3<T> {
+    /// Returns `None` if the matrix is singular, or so close to it that the
+    /// determinant is within rounding error of zero.
+    pub fn inverse(self) -> Option<Self> {
+        let det = self.determinant();
+        let [x, y, z] = self.cols;
+        // The determinant is at most the product of the column lengths, and
+        // rounding errors scale with it.
+        if det.abs() <= T::EPSILON * x.length() * y.length() * z.length() {
+            return None;
+        }
+        // The rows of the inverse are the cross products of the columns.
+        let inverse = Self::from_rows(y.cross(z), z.cross(x), x.cross(y));
+        Some(inverse * (T::ONE / det))
+    }
+}
+
+impl<T: Scalar> Add for Matrix3<T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::from_cols(
+            self.cols[0] + other.cols[0],
+            self.cols[1] + other.cols[1],
+            self.cols[2] + other.cols[2],
+        )
+    }
+}
+
+impl<T: Scalar> Sub for Matrix3<T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::from_cols(
+            self.cols[0] - other.cols[0],
+            self.cols[1] - other.cols[1],
+            self.cols[2] - other.cols[2],
+        )
+    }
+}
+
+impl<T: Scalar> Mul<T> for Matrix3<T> {
+    type Output = Self;
+
+    fn mul(self, other: T) -> Self {
+        Self::from_cols(
+            self.cols[0] * other,
+            self.cols[1] * other,
+            self.cols[2] * other,
+        )
+    }
+}
+
+impl<T: Scalar> Mul<Vector3<T>> for Matrix3<T> {
+    type Output = Vector3<T>;
+
+    fn mul(self, other: Vector3<T>) -> Vector3<T> {
+        self.cols[0] * other.x + self.cols[1] * other.y + self.cols[2] * other.z
+    }
+}
+
+impl<T: Scalar> Mul for Matrix3<T> {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        Self::from_cols(
+            self * other.cols[0],
+            self * other.cols[1],
+            self * other.cols[2],
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Matrix3 {
+        Matrix3::from_rows(
+            Vector3::new(2.0, 0.0, 1.0),
+            Vector3::new(1.0, 1.0, 0.0),
+            Vector3::new(0.0, 3.0, 1.0),
+        )
+    }
+
+    #[test]
+    fn test_matrix3_from_cols() {
+        let m = Matrix3::from_cols(
+            Vector3::new(1.0, 2.0, 3.0),
+            Vector3::new(4.0, 5.0, 6.0),
+            Vector3::new(7.0, 8.0, 9.0),
+        );
+        assert_eq!(m * Vector3::new(1.0, 0.0, 0.0), Vector3::new(1.0, 2.0, 3.0));
+        assert_eq!(m * Vector3::new(0.0, 0.0, 1.0), Vector3::new(7.0, 8.0, 9.0));
+    }
+
+    #[test]
+    fn test_matrix3_transpose() {
+        let m = sample();
+        assert_eq!(m.transpose().cols[0], Vector3::new(2.0, 0.0, 1.0));
+        assert_eq!(m.transpose().transpose(), m);
+    }
+
+    #[test]
+    fn test_matrix3_mul() {
+        let m = sample();
+        assert_eq!(m * Matrix3::IDENTITY, m);
+        assert_eq!(Matrix3::IDENTITY * m, m);
+        assert_eq!(m * Vector3::new(1.0, 2.0, 3.0), Vector3::new(5.0, 3.0, 9.0));
+
+        let scale = Matrix3::from_diagonal(Vector3::new(2.0, 3.0, 4.0));
+        assert_eq!((scale * m).cols[0], Vector3::new(4.0, 3.0, 0.0));
+    }
+
+    #[test]
+    fn test_matrix3_determinant() {
+        assert_eq!(sample().determinant(), 5.0);
+        assert_eq!(Matrix3::<f64>::IDENTITY.determinant(), 1.0);
+        assert_eq!(
+            Matrix3::from_diagonal(Vector3::new(2, 3, 4)).determinant(),
+            24
+        );
+    }
+
+    #[test]
+    fn test_matrix3_inverse() {
+        let m = Matrix3::from_diagonal(Vector3::new(2.0, 4.0, 8.0));
+        let inverse = m.inverse().unwrap();
+        assert_eq!(
+            inverse,
+            Matrix3::from_diagonal(Vector3::new(0.5, 0.25, 0.125))
+        );
+        assert_eq!(m * inverse, Matrix3::IDENTITY);
+
+        let m = Matrix3::from_rows(
+            Vector3::new(1.0, 2.0, 0.0),
+            Vector3::new(0.0, 1.0, 0.0),
+            Vector3::new(0.0, 0.0, 1.0),
+        );
+        assert_eq!(m * m.inverse().unwrap(), Matrix3::IDENTITY);
+    }
+
+    #[test]
+    fn test_matrix3_inverse_singular() {
+        let m = Matrix3::from_cols(
+            Vector3::new(1.0, 2.0, 3.0),
+            Vector3::new(2.0, 4.0, 6.0),
+            Vector3::new(0.0, 0.0, 1.0),
+        );
+        assert_eq!(m.inverse(), None);
+        assert_eq!(Matrix3::<f64>::ZERO.inverse(), None);
+
+        // Singular, but the determinant rounds to about -7e-18.
+        let m = Matrix3::from_rows(
+            Vector3::new(0.1, 0.2, 0.3),
+            Vector3::new(0.4, 0.5, 0.6),
+            Vector3::new(0.7, 0.8, 0.9),
+        );
+        assert_ne!(m.determinant(), 0.0);
+        assert_eq!(m.inverse(), None);
+
+        // Small but well-conditioned matrices still invert.
+        let m = Matrix3::from_diagonal(Vector3::new(1e-8, 1e-8, 1.0));
+        assert_vec_approx_eq!(
+            m.inverse().unwrap(),
+            Matrix3::from_diagonal(Vector3::new(1e8, 1e8, 1.0)),
+            max_relative = 1e-15
+        );
+    }
+}
@@ -0,0 +1,329 @@
+use std::ops::{Add, Mul, Sub};
+
+use crate::{Float, Matrix3, Scalar, Vector3, Vector4};
+
+/// A 4x4 column-major matrix.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[repr(C)]
+pub struct Matrix4<T = f64> {
+    pub cols: [Vector4<T>; 4],
+}
+
+impl<T: Scalar> Matrix4<T> {
+    pub const ZERO: Self = Self {
+        cols: [Vector4::ZERO; 4],
+    };
+
+    pub const IDENTITY: Self = Self {
+        cols: [
+            Vector4 {
+                x: T::ONE,
+                y: T::ZERO,
+                z: T::ZERO,
+                w: T::ZERO,
+            },
+            Vector4 {
+                x: T::ZERO,
+                y: T::ONE,
+                z: T::ZERO,
+                w: T::ZERO,
+            },
+            Vector4 {
+                x: T::ZERO,
+                y: T::ZERO,
+                z: T::ONE,
+                w: T::ZERO,
+            },
+            Vector4 {
+                x: T::ZERO,
+                y: T::ZERO,
+                z: T::ZERO,
+                w: T::ONE,
+            },
+        ],
+    };
+
+    pub fn from_cols(x: Vector4<T>, y: Vector4<T>, z: Vector4<T>, w: Vector4<T>) -> Self {
+        Self { cols: [x, y, z, w] }
+    }
+
+    pub fn from_rows(x: Vector4<T>, y: Vector4<T>, z: Vector4<T>, w: Vector4<T>) -> Self {
+        Self::from_cols(x, y, z, w).transpose()
+    }
+
+    pub fn from_cols_array(m: [[T; 4]; 4]) -> Self {
+        let col = |c: [T; 4]| Vector4::new(c[0], c[1], c[2], c[3]);
+        Self::from_cols(col(m[0]), col(m[1]), col(m[2]), col(m[3]))
+    }
+
+    pub fn to_cols_array(self) -> [[T; 4]; 4] {
+        self.cols.map(|c| [c.x, c.y, c.z, c.w])
+    }
+
+    /// Builds an affine transform from a linear part and a translation.
+    pub fn from_matrix3_translation(linear: Matrix3<T>, translation: Vector3<T>) -> Self {
+        let [x, y, z] = linear.cols;
+        Self::from_cols(
+            x.extend(T::ZERO),
+            y.extend(T::ZERO),
+            z.extend(T::ZERO),
+            translation.extend(T::ONE),
+        )
+    }
+
+    pub fn from_translation(translation: Vector3<T>) -> Self {
+        Self::from_matrix3_translation(Matrix3::IDENTITY, translation)
+    }
+
+    pub fn transpose(self) -> Self {
+        let m = self.to_cols_array();
+        let mut t = m;
+        for (c, col) in t.iter_mut().enumerate() {
+            for (r, value) in col.iter_mut().enumerate() {
+                *value = m[r][c];
+            }
+        }
+        Self::from_cols_array(t)
+    }
+
+    /// Returns the 2x2 minors used by both `determinant` and `inverse`.
+    fn minors(a: &[[T; 4]; 4]) -> ([T; 6], [T; 6]) {
+        let s = [
+            a[0][0] * a[1][1] - a[1][0] * a[0][1],
+            a[0][0] * a[1][2] - a[1][0] * a[0][2],
+            a[0][0] * a[1][3] - a[1][0] * a[0][3],
+            a[0][1] * a[1][2] - a[1][1] * a[0][2],
+            a[0][1] * a[1][3] - a[1][1] * a[0][3],
+            a[0][2] * a[1][3] - a[1][2] * a[0][3],
+        ];
+        let c = [
+            a[2][0] * a[3][1] - a[3][0] * a[2][1],
+            a[2][0] * a[3][2] - a[3][0] * a[2][2],
+            a[2][0] * a[3][3] - a[3][0] * a[2][3],
+            a[2][1] * a[3][2] - a[3][1] * a[2][2],
+            a[2][1] * a[3][3] - a[3][1] * a[2][3],
+            a[2][2] * a[3][3] - a[3][2] * a[2][3],
+        ];
+        (s, c)
+    }
+
+    fn determinant_from_minors(s: &[T; 6], c: &[T; 6]) -> T {
+        s[0] * c[5] - s[1] * c[4] + s[2] * c[3] + s[3] * c[2] - s[4] * c[1] + s[5] * c[0]
+    }
+
+    pub fn determinant(self) -> T {
+        let (s, c) = Self::minors(&self.to_cols_array());
+        Self::determinant_from_minors(&s, &c)
+    }
+
+    /// Applies the matrix to `point` with an implicit `w = 1` and divides by
+    /// the resulting `w`.
+    pub fn transform_point(self, point: Vector3<T>) -> Vector3<T>
+    where
+        T: Float,
+    {
+        let v = self * point.extend(T::ONE);
+        v.truncate() / v.w
+    }
+
+    /// Applies the matrix to `vector` with an implicit `w = 0`, ignoring the
+    /// translation.
+    pub fn transform_vector(self, vector: Vector3<T>) -> Vector3<T> {
+        (self * vector.extend(T::ZERO)).truncate()
+    }
+}
+
+impl<T: Float> Matrix4<T> {
+    /// Returns `None` if the matrix is singular, or so close to it that the
+    /// determinant is within rounding error of zero.
+    pub fn inverse(self) -> Option<Self> {
+        // Laplace expansion by 2x2 minors. The formula is symmetric under
+        // transposition, so it applies directly to the column-major layout.
+        let a = self.to_cols_array();
+        let (s, c) = Self::minors(&a);
+        let det = Self::determinant_from_minors(&s, &c);
+        let scale = self.cols.iter().fold(T::ONE, |p, c| p * c.length());
+        if det.abs() <= T::EPSILON * scale {
+            return None;
+        }
+        let b = [
+            [
+                a[1][1] * c[5] - a[1][2] * c[4] + a[1][3] * c[3],
+                -a[0][1] * c[5] + a[0][2] * c[4] - a[0][3] * c[3],
+                a[3][1] * s[5] - a[3][2] * s[4] + a[3][3] * s[3],
+                -a[2][1] * s[5] + a[2][2] * s[4] - a[2][3] * s[3],
+            ],
+            [
+                -a[1][0] * c[5] + a[1][2] * c[2] - a[1][3] * c[1],
+                a[0][0] * c[5] - a[0][2] * c[2] + a[0][3] * c[1],
+                -a[3][0] * s[5] + a[3][2] * s[2] - a[3][3] * s[1],
+                a[2][0] * s[5] - a[2][2] * s[2] + a[2][3] * s[1],
+            ],
+            [
+                a[1][0] * c[4] - a[1][1] * c[2] + a[1][3] * c[0],
+                -a[0][0] * c[4] + a[0][1] * c[2] - a[0][3] * c[0],
+                a[3][0] * s[4] - a[3][1] * s[2] + a[3][3] * s[0],
+                -a[2][0] * s[4] + a[2][1] * s[2] - a[2][3] * s[0],
+            ],
+            [
+                -a[1][0] * c[3] + a[1][1] * c[1] - a[1][2] * c[0],
+                a[0][0] * c[3] - a[0][1] * c[1] + a[0][2] * c[0],
+                -a[3][0] * s[3] + a[3][1] * s[1] - a[3][2] * s[0],
+                a[2][0] * s[3] - a[2][1] * s[1] + a[2][2] * s[0],
+            ],
+        ];
+        Some(Self::from_cols_array(b) * (T::ONE / det))
+    }
+}
+
+impl<T: Scalar> Add for Matrix4<T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::from_cols(
+            self.cols[0] + other.cols[0],
+            self.cols[1] + other.cols[1],
+            self.cols[2] + other.cols[2],
+            self.cols[3] + other.cols[3],
+        )
+    }
+}
+
+impl<T: Scalar> Sub for Matrix4<T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::from_cols(
+            self.cols[0] - other.cols[0],
+            self.cols[1] - other.cols[1],
+            self.cols[2] - other.cols[2],
+            self.cols[3] - other.cols[3],
+        )
+    }
+}
+
+impl<T: Scalar> Mul<T> for Matrix4<T> {
+    type Output = Self;
+
+    fn mul(self, other: T) -> Self {
+        Self {
+            cols: self.cols.map(|c| c * other),
+        }
+    }
+}
+
+impl<T: Scalar> Mul<Vector4<T>> for Matrix4<T> {
+    type Output = Vector4<T>;
+
+    fn mul(self, other: Vector4<T>) -> Vector4<T> {
+        self.cols[0] * other.x
+            + self.cols[1] * other.y
+            + self.cols[2] * other.z
+            + self.cols[3] * other.w
+    }
+}
+
+impl<T: Scalar> Mul for Matrix4<T> {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        Self {
+            cols: other.cols.map(|c| self * c),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Matrix4 {
+        Matrix4::from_rows(
+            Vector4::new(1.0, 0.0, 2.0, 0.0),
+            Vector4::new(0.0, 2.0, 0.0, 1.0),
+            Vector4::new(1.0, 0.0, 0.0, 0.0),
+            Vector4::new(0.0, 1.0, 0.0, 1.0),
+        )
+    }
+
+    #[test]
+    fn test_matrix4_transpose() {
+        let m = sample();
+        assert_eq!(m.transpose().cols[0], Vector4::new(1.0, 0.0, 2.0, 0.0));
+        assert_eq!(m.transpose().transpose(), m);
+    }
+
+    #[test]
+    fn test_matrix4_mul() {
+        let m = sample();
+        assert_eq!(m * Matrix4::IDENTITY, m);
+        assert_eq!(Matrix4::IDENTITY * m, m);
+        assert_eq!(
+            m * Vector4::new(1.0, 2.0, 3.0, 4.0),
+            Vector4::new(7.0, 8.0, 1.0, 6.0)
+        );
+    }
+
+    #[test]
+    fn test_matrix4_determinant() {
+        assert_eq!(sample().determinant(), -2.0);
+        assert_eq!(Matrix4::<f64>::IDENTITY.determinant(), 1.0);
+        assert_eq!(
+            Matrix4::from_translation(Vector3::new(1, 2, 3)).determinant(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_matrix4_inverse() {
+        let m = sample();
+        let inverse = m.inverse().unwrap();
+        assert_eq!(m * inverse, Matrix4::IDENTITY);
+        assert_eq!(inverse * m, Matrix4::IDENTITY);
+
+        let t = Matrix4::from_translation(Vector3::new(1.0, 2.0, 3.0));
+        assert_eq!(
+            t.inverse().unwrap(),
+            Matrix4::from_translation(Vector3::new(-1.0, -2.0, -3.0))
+        );
+    }
+
+    #[test]
+    fn test_matrix4_inverse_singular() {
+        let m = Matrix4::from_rows(
+            Vector4::new(1.0, 2.0, 3.0, 4.0),
+            Vector4::new(2.0, 4.0, 6.0, 8.0),
+            Vector4::new(0.0, 0.0, 1.0, 0.0),
+            Vector4::new(0.0, 0.0, 0.0, 1.0),
+        );
+        assert_eq!(m.inverse(), None);
+
+        // Singular, but the determinant doesn't round to zero.
+        let m = Matrix4::from_rows(
+            Vector4::new(0.1, 0.2, 0.3, 0.4),
+            Vector4::new(0.5, 0.6, 0.7, 0.8),
+            Vector4::new(0.9, 1.0, 1.1, 1.2),
+            Vector4::new(0.0, 0.0, 0.0, 1.0),
+        );
+        assert_ne!(m.determinant(), 0.0);
+        assert_eq!(m.inverse(), None);
+    }
+
+    #[test]
+    fn test_matrix4_transform() {
+        let linear = Matrix3::from_diagonal(Vector3::new(2.0, 2.0, 2.0));
+        let m = Matrix4::from_matrix3_translation(linear, Vector3::new(1.0, 2.0, 3.0));
+        let v = Vector3::new(1.0, 1.0, 1.0);
+        assert_eq!(m.transform_point(v), Vector3::new(3.0, 4.0, 5.0));
+        assert_eq!(m.transform_vector(v), Vector3::new(2.0, 2.0, 2.0));
+
+        // A projective matrix that copies z into w.
+        let mut p = Matrix4::IDENTITY;
+        p.cols[2].w = 1.0;
+        p.cols[3].w = 0.0;
+        assert_eq!(
+            p.transform_point(Vector3::new(2.0, 4.0, 2.0)),
+            Vector3::new(1.0, 2.0, 1.0)
+        );
+    }
+}
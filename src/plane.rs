@@ -0,0 +1,101 @@
+use crate::{Ray, RayHit, UnitVector3, Vector3};
+
+/// The plane of points `p` with `normal.dot(p) + d == 0`.
+///
+/// The normal points to the front of the plane; the half-space behind it
+/// counts as inside.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Plane {
+    pub normal: UnitVector3,
+    pub d: f64,
+}
+
+impl Plane {
+    pub fn new(normal: UnitVector3, d: f64) -> Self {
+        Self { normal, d }
+    }
+
+    pub fn from_point_normal(point: Vector3, normal: UnitVector3) -> Self {
+        Self::new(normal, -normal.dot(point))
+    }
+
+    /// Positive in front of the plane, negative behind it.
+    pub fn signed_distance(self, point: Vector3) -> f64 {
+        self.normal.dot(point) + self.d
+    }
+
+    pub fn contains(self, point: Vector3) -> bool {
+        self.signed_distance(point) <= 0.0
+    }
+
+    /// The projection of `point` onto the plane.
+    pub fn closest_point(self, point: Vector3) -> Vector3 {
+        point - *self.normal * self.signed_distance(point)
+    }
+
+    pub fn intersect_ray(self, ray: Ray) -> Option<RayHit> {
+        let denominator = self.normal.dot(*ray.direction);
+        if denominator == 0.0 {
+            return None;
+        }
+        let t = -self.signed_distance(ray.origin) / denominator;
+        if t < 0.0 {
+            return None;
+        }
+        let normal = if denominator > 0.0 {
+            -self.normal
+        } else {
+            self.normal
+        };
+        Some(ray.hit(t, normal))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ground() -> Plane {
+        Plane::from_point_normal(Vector3::new(0.0, 1.0, 0.0), UnitVector3::Y)
+    }
+
+    #[test]
+    fn test_plane_signed_distance() {
+        let plane = ground();
+        assert_eq!(plane.d, -1.0);
+        assert_eq!(plane.signed_distance(Vector3::new(5.0, 3.0, -2.0)), 2.0);
+        assert_eq!(plane.signed_distance(Vector3::new(5.0, -1.0, -2.0)), -2.0);
+        assert!(plane.contains(Vector3::new(0.0, 0.5, 0.0)));
+        assert!(!plane.contains(Vector3::new(0.0, 1.5, 0.0)));
+        assert_eq!(
+            plane.closest_point(Vector3::new(5.0, 3.0, -2.0)),
+            Vector3::new(5.0, 1.0, -2.0)
+        );
+    }
+
+    #[test]
+    fn test_plane_intersect_ray() {
+        let plane = ground();
+        let down = UnitVector3::new_unchecked(Vector3::new(0.0, -1.0, 0.0));
+        let hit = plane
+            .intersect_ray(Ray::new(Vector3::new(2.0, 5.0, 0.0), down))
+            .unwrap();
+        assert_eq!(hit.distance, 4.0);
+        assert_eq!(hit.point, Vector3::new(2.0, 1.0, 0.0));
+        assert_eq!(hit.normal, UnitVector3::Y);
+
+        // From below, the reported normal faces the ray.
+        let hit = plane
+            .intersect_ray(Ray::new(Vector3::ZERO, UnitVector3::Y))
+            .unwrap();
+        assert_eq!(hit.distance, 1.0);
+        assert_eq!(hit.normal, down);
+
+        // Pointing away or parallel.
+        assert_eq!(plane.intersect_ray(Ray::new(Vector3::ZERO, down)), None);
+        assert_eq!(
+            plane.intersect_ray(Ray::new(Vector3::ZERO, UnitVector3::X)),
+            None
+        );
+    }
+}
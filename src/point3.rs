@@ -0,0 +1,136 @@
+use std::ops::{Add, Mul, Sub};
+
+use crate::{Float, Matrix3, Matrix4, Scalar, Vector3};
+
+/// A position in space, as opposed to a displacement `Vector3`.
+///
+/// Points follow affine rules: the difference of two points is a vector and
+/// a point can be offset by a vector, but points can't be added together.
+///
+/// ```compile_fail
+/// use vector3::Point3;
+///
+/// let p = Point3::new(1.0, 2.0, 3.0);
+/// let _ = p + p;
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[repr(C)]
+pub struct Point3<T = f64> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T: Scalar> Point3<T> {
+    pub const ORIGIN: Self = Self {
+        x: T::ZERO,
+        y: T::ZERO,
+        z: T::ZERO,
+    };
+
+    pub fn new(x: T, y: T, z: T) -> Self {
+        Self { x, y, z }
+    }
+
+    /// The point reached by displacing the origin by `v`.
+    pub fn from_vector(v: Vector3<T>) -> Self {
+        Self::new(v.x, v.y, v.z)
+    }
+
+    /// The displacement of this point from the origin.
+    pub fn to_vector(self) -> Vector3<T> {
+        Vector3::new(self.x, self.y, self.z)
+    }
+}
+
+impl<T: Float> Point3<T> {
+    pub fn distance(self, other: Self) -> T {
+        (self - other).length()
+    }
+}
+
+impl<T: Scalar> Sub for Point3<T> {
+    type Output = Vector3<T>;
+
+    fn sub(self, other: Self) -> Vector3<T> {
+        self.to_vector() - other.to_vector()
+    }
+}
+
+impl<T: Scalar> Add<Vector3<T>> for Point3<T> {
+    type Output = Self;
+
+    fn add(self, other: Vector3<T>) -> Self {
+        Self::from_vector(self.to_vector() + other)
+    }
+}
+
+impl<T: Scalar> Sub<Vector3<T>> for Point3<T> {
+    type Output = Self;
+
+    fn sub(self, other: Vector3<T>) -> Self {
+        Self::from_vector(self.to_vector() - other)
+    }
+}
+
+impl<T: Scalar> Mul<Point3<T>> for Matrix3<T> {
+    type Output = Point3<T>;
+
+    fn mul(self, other: Point3<T>) -> Point3<T> {
+        Point3::from_vector(self * other.to_vector())
+    }
+}
+
+/// Transforms a point with `w = 1`, so translations apply.
+impl<T: Float> Mul<Point3<T>> for Matrix4<T> {
+    type Output = Point3<T>;
+
+    fn mul(self, other: Point3<T>) -> Point3<T> {
+        Point3::from_vector(self.transform_point(other.to_vector()))
+    }
+}
+
+/// Transforms a vector with `w = 0`, so translations are ignored.
+impl<T: Scalar> Mul<Vector3<T>> for Matrix4<T> {
+    type Output = Vector3<T>;
+
+    fn mul(self, other: Vector3<T>) -> Vector3<T> {
+        self.transform_vector(other)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_point_affine_ops() {
+        let p = Point3::new(1.0, 2.0, 3.0);
+        let q = Point3::new(4.0, 6.0, 3.0);
+        assert_eq!(q - p, Vector3::new(3.0, 4.0, 0.0));
+        assert_eq!(p + (q - p), q);
+        assert_eq!(q - (q - p), p);
+        assert_eq!(p.distance(q), 5.0);
+    }
+
+    #[test]
+    fn test_point_vector_conversion() {
+        let v = Vector3::new(1, 2, 3);
+        assert_eq!(Point3::from_vector(v), Point3::new(1, 2, 3));
+        assert_eq!(Point3::from_vector(v).to_vector(), v);
+        assert_eq!(Point3::ORIGIN + v, Point3::from_vector(v));
+    }
+
+    #[test]
+    fn test_point_transform() {
+        let m = Matrix4::from_translation(Vector3::new(1.0, 2.0, 3.0));
+        assert_eq!(m * Point3::new(1.0, 1.0, 1.0), Point3::new(2.0, 3.0, 4.0));
+        assert_eq!(m * Vector3::new(1.0, 1.0, 1.0), Vector3::new(1.0, 1.0, 1.0));
+
+        let scale = Matrix3::from_diagonal(Vector3::new(2.0, 3.0, 4.0));
+        assert_eq!(
+            scale * Point3::new(1.0, 1.0, 1.0),
+            Point3::new(2.0, 3.0, 4.0)
+        );
+    }
+}
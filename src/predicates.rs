@@ -0,0 +1,506 @@
+//! Robust geometric predicates after Shewchuk, "Adaptive Precision
+//! Floating-Point Arithmetic and Fast Robust Geometric Predicates" (1997).
+//!
+//! Each predicate returns a value whose sign is exactly that of the
+//! determinant it is named after, for any finite input. It first evaluates
+//! the determinant in ordinary floating point and returns that if it is
+//! larger than Shewchuk's bound on the rounding error. Only nearly
+//! degenerate inputs fall through to an exact evaluation with floating-point
+//! expansions, in which case the returned magnitude is an approximation but
+//! the sign is still exact. Overflow and underflow are not handled.
+
+use std::ops::{Add, Mul, Neg, Sub};
+
+use crate::{Vector2, Vector3};
+
+/// Half an ulp of one, the unit roundoff of `f64`.
+const EPSILON: f64 = f64::EPSILON / 2.0;
+const ORIENT2D_BOUND: f64 = (3.0 + 16.0 * EPSILON) * EPSILON;
+const ORIENT3D_BOUND: f64 = (7.0 + 56.0 * EPSILON) * EPSILON;
+const INCIRCLE_BOUND: f64 = (10.0 + 96.0 * EPSILON) * EPSILON;
+const INSPHERE_BOUND: f64 = (16.0 + 224.0 * EPSILON) * EPSILON;
+
+/// Positive if `a`, `b` and `c` are in counter-clockwise order, negative if
+/// clockwise and zero if collinear.
+pub fn orient2d(a: Vector2, b: Vector2, c: Vector2) -> f64 {
+    let left = (a.x - c.x) * (b.y - c.y);
+    let right = (a.y - c.y) * (b.x - c.x);
+    let det = left - right;
+    let bound = ORIENT2D_BOUND * (left.abs() + right.abs());
+    if det.abs() >= bound {
+        return det;
+    }
+    orient2d_exact(a, b, c)
+}
+
+/// Positive if `d` lies below the plane through `a`, `b` and `c`, where
+/// "below" is the side from which they appear clockwise; negative if above
+/// and zero if the four points are coplanar.
+///
+/// This is the determinant of the rows `a - d`, `b - d` and `c - d`, and so
+/// has the opposite sign to `((b - a).cross(c - a)).dot(d - a)`.
+pub fn orient3d(a: Vector3, b: Vector3, c: Vector3, d: Vector3) -> f64 {
+    let (ad, bd, cd) = (a - d, b - d, c - d);
+    let (bdx_cdy, cdx_bdy) = (bd.x * cd.y, cd.x * bd.y);
+    let (cdx_ady, adx_cdy) = (cd.x * ad.y, ad.x * cd.y);
+    let (adx_bdy, bdx_ady) = (ad.x * bd.y, bd.x * ad.y);
+    let det = ad.z * (bdx_cdy - cdx_bdy) + bd.z * (cdx_ady - adx_cdy) + cd.z * (adx_bdy - bdx_ady);
+    let permanent = (bdx_cdy.abs() + cdx_bdy.abs()) * ad.z.abs()
+        + (cdx_ady.abs() + adx_cdy.abs()) * bd.z.abs()
+        + (adx_bdy.abs() + bdx_ady.abs()) * cd.z.abs();
+    let bound = ORIENT3D_BOUND * permanent;
+    if det.abs() >= bound {
+        return det;
+    }
+    orient3d_exact(a, b, c, d)
+}
+
+/// Positive if `d` lies inside the circle through `a`, `b` and `c`, negative
+/// if outside and zero if the four points are cocircular. `a`, `b` and `c`
+/// must be in counter-clockwise order, or the sign is reversed.
+pub fn incircle(a: Vector2, b: Vector2, c: Vector2, d: Vector2) -> f64 {
+    let (ad, bd, cd) = (a - d, b - d, c - d);
+    let (bdx_cdy, cdx_bdy) = (bd.x * cd.y, cd.x * bd.y);
+    let (cdx_ady, adx_cdy) = (cd.x * ad.y, ad.x * cd.y);
+    let (adx_bdy, bdx_ady) = (ad.x * bd.y, bd.x * ad.y);
+    let (a_lift, b_lift, c_lift) = (ad.dot(ad), bd.dot(bd), cd.dot(cd));
+    let det =
+        a_lift * (bdx_cdy - cdx_bdy) + b_lift * (cdx_ady - adx_cdy) + c_lift * (adx_bdy - bdx_ady);
+    let permanent = (bdx_cdy.abs() + cdx_bdy.abs()) * a_lift
+        + (cdx_ady.abs() + adx_cdy.abs()) * b_lift
+        + (adx_bdy.abs() + bdx_ady.abs()) * c_lift;
+    let bound = INCIRCLE_BOUND * permanent;
+    if det.abs() >= bound {
+        return det;
+    }
+    incircle_exact(a, b, c, d)
+}
+
+/// Positive if `e` lies inside the sphere through `a`, `b`, `c` and `d`,
+/// negative if outside and zero if the five points are cospherical. The
+/// first four points must have a positive `orient3d`, or the sign is
+/// reversed.
+pub fn insphere(a: Vector3, b: Vector3, c: Vector3, d: Vector3, e: Vector3) -> f64 {
+    let (ae, be, ce, de) = (a - e, b - e, c - e, d - e);
+    let (aex_bey, bex_aey) = (ae.x * be.y, be.x * ae.y);
+    let (bex_cey, cex_bey) = (be.x * ce.y, ce.x * be.y);
+    let (cex_dey, dex_cey) = (ce.x * de.y, de.x * ce.y);
+    let (dex_aey, aex_dey) = (de.x * ae.y, ae.x * de.y);
+    let (aex_cey, cex_aey) = (ae.x * ce.y, ce.x * ae.y);
+    let (bex_dey, dex_bey) = (be.x * de.y, de.x * be.y);
+    let ab = aex_bey - bex_aey;
+    let bc = bex_cey - cex_bey;
+    let cd = cex_dey - dex_cey;
+    let da = dex_aey - aex_dey;
+    let ac = aex_cey - cex_aey;
+    let bd = bex_dey - dex_bey;
+
+    let abc = ae.z * bc - be.z * ac + ce.z * ab;
+    let bcd = be.z * cd - ce.z * bd + de.z * bc;
+    let cda = ce.z * da + de.z * ac + ae.z * cd;
+    let dab = de.z * ab + ae.z * bd + be.z * da;
+    let (a_lift, b_lift) = (ae.dot(ae), be.dot(be));
+    let (c_lift, d_lift) = (ce.dot(ce), de.dot(de));
+    let det = (d_lift * abc - c_lift * dab) + (b_lift * cda - a_lift * bcd);
+
+    let [aez, bez, cez, dez] = [ae.z, be.z, ce.z, de.z].map(f64::abs);
+    let plus = |x: f64, y: f64| x.abs() + y.abs();
+    let permanent = ((plus(cex_dey, dex_cey) * bez
+        + plus(dex_bey, bex_dey) * cez
+        + plus(bex_cey, cex_bey) * dez)
+        * a_lift)
+        + ((plus(dex_aey, aex_dey) * cez
+            + plus(aex_cey, cex_aey) * dez
+            + plus(cex_dey, dex_cey) * aez)
+            * b_lift)
+        + ((plus(aex_bey, bex_aey) * dez
+            + plus(bex_dey, dex_bey) * aez
+            + plus(dex_aey, aex_dey) * bez)
+            * c_lift)
+        + ((plus(bex_cey, cex_bey) * aez
+            + plus(cex_aey, aex_cey) * bez
+            + plus(aex_bey, bex_aey) * cez)
+            * d_lift);
+    let bound = INSPHERE_BOUND * permanent;
+    if det.abs() >= bound {
+        return det;
+    }
+    insphere_exact(a, b, c, d, e)
+}
+
+/// The normal `(b - a).cross(c - a)` of the triangle `a`, `b`, `c`, with each
+/// component rounded once from its exact value.
+///
+/// The plain cross product loses all relative accuracy for nearly collinear
+/// points, which leaves thin triangles with normals pointing anywhere.
+pub(crate) fn triangle_normal(a: Vector3, b: Vector3, c: Vector3) -> Vector3 {
+    let [u, v] = exact_rows([b, c], a);
+    let component = |i: usize, j: usize| (&(&u[i] * &v[j]) - &(&u[j] * &v[i])).value();
+    Vector3::new(component(1, 2), component(2, 0), component(0, 1))
+}
+
+fn orient2d_exact(a: Vector2, b: Vector2, c: Vector2) -> f64 {
+    let acx = Expansion::diff(a.x, c.x);
+    let acy = Expansion::diff(a.y, c.y);
+    let bcx = Expansion::diff(b.x, c.x);
+    let bcy = Expansion::diff(b.y, c.y);
+    (&(&acx * &bcy) - &(&acy * &bcx)).estimate()
+}
+
+/// The rows of the differences `p - origin`, as exact expansions.
+fn exact_rows<const N: usize>(points: [Vector3; N], origin: Vector3) -> [[Expansion; 3]; N] {
+    points.map(|p| {
+        [
+            Expansion::diff(p.x, origin.x),
+            Expansion::diff(p.y, origin.y),
+            Expansion::diff(p.z, origin.z),
+        ]
+    })
+}
+
+/// The 2x2 minor `p.x * q.y - q.x * p.y`.
+fn minor(p: &[Expansion; 3], q: &[Expansion; 3]) -> Expansion {
+    &(&p[0] * &q[1]) - &(&q[0] * &p[1])
+}
+
+fn lift(p: &[Expansion; 3]) -> Expansion {
+    &(&(&p[0] * &p[0]) + &(&p[1] * &p[1])) + &(&p[2] * &p[2])
+}
+
+fn orient3d_exact(a: Vector3, b: Vector3, c: Vector3, d: Vector3) -> f64 {
+    let [a, b, c] = exact_rows([a, b, c], d);
+    let det = &(&(&a[2] * &minor(&b, &c)) + &(&b[2] * &minor(&c, &a))) + &(&c[2] * &minor(&a, &b));
+    det.estimate()
+}
+
+fn incircle_exact(a: Vector2, b: Vector2, c: Vector2, d: Vector2) -> f64 {
+    let flat = |p: Vector2| Vector3::new(p.x, p.y, 0.0);
+    let [a, b, c] = exact_rows([flat(a), flat(b), flat(c)], flat(d));
+    let det = &(&(&lift(&a) * &minor(&b, &c)) + &(&lift(&b) * &minor(&c, &a)))
+        + &(&lift(&c) * &minor(&a, &b));
+    det.estimate()
+}
+
+fn insphere_exact(a: Vector3, b: Vector3, c: Vector3, d: Vector3, e: Vector3) -> f64 {
+    let [a, b, c, d] = exact_rows([a, b, c, d], e);
+    // The same cofactor expansion as the fast path.
+    let (ab, bc, cd, da) = (minor(&a, &b), minor(&b, &c), minor(&c, &d), minor(&d, &a));
+    let (ac, bd) = (minor(&a, &c), minor(&b, &d));
+    let abc = &(&(&a[2] * &bc) - &(&b[2] * &ac)) + &(&c[2] * &ab);
+    let bcd = &(&(&b[2] * &cd) - &(&c[2] * &bd)) + &(&d[2] * &bc);
+    let cda = &(&(&c[2] * &da) + &(&d[2] * &ac)) + &(&a[2] * &cd);
+    let dab = &(&(&d[2] * &ab) + &(&a[2] * &bd)) + &(&b[2] * &da);
+    let left = &(&lift(&d) * &abc) - &(&lift(&c) * &dab);
+    let right = &(&lift(&b) * &cda) - &(&lift(&a) * &bcd);
+    (&left + &right).estimate()
+}
+
+/// An exact sum of nonoverlapping `f64` components in increasing order of
+/// magnitude, with zero components removed.
+#[derive(Debug, Clone)]
+struct Expansion(Vec<f64>);
+
+/// `a + b` exactly, as the rounded sum and its error.
+fn two_sum(a: f64, b: f64) -> (f64, f64) {
+    let x = a + b;
+    let b_virtual = x - a;
+    let a_virtual = x - b_virtual;
+    (x, (a - a_virtual) + (b - b_virtual))
+}
+
+/// `a * b` exactly, as the rounded product and its error.
+fn two_product(a: f64, b: f64) -> (f64, f64) {
+    let x = a * b;
+    (x, a.mul_add(b, -x))
+}
+
+impl Expansion {
+    fn diff(a: f64, b: f64) -> Self {
+        let (x, y) = two_sum(a, -b);
+        Self::from_components([y, x])
+    }
+
+    fn from_components(components: impl IntoIterator<Item = f64>) -> Self {
+        Self(components.into_iter().filter(|&c| c != 0.0).collect())
+    }
+
+    /// Adds one component, following Shewchuk's `GROW-EXPANSION`.
+    fn grow(&self, b: f64) -> Self {
+        let mut q = b;
+        let mut h = Vec::with_capacity(self.0.len() + 1);
+        for &e in &self.0 {
+            let (sum, error) = two_sum(q, e);
+            h.push(error);
+            q = sum;
+        }
+        h.push(q);
+        Self::from_components(h)
+    }
+
+    /// Multiplies by one component, following Shewchuk's
+    /// `SCALE-EXPANSION`.
+    fn scale(&self, b: f64) -> Self {
+        let Some((&first, rest)) = self.0.split_first() else {
+            return Self(Vec::new());
+        };
+        let mut h = Vec::with_capacity(2 * self.0.len());
+        let (mut q, error) = two_product(first, b);
+        h.push(error);
+        for &e in rest {
+            let (high, low) = two_product(e, b);
+            let (sum, error) = two_sum(q, low);
+            h.push(error);
+            let (sum, error) = two_sum(high, sum);
+            h.push(error);
+            q = sum;
+        }
+        h.push(q);
+        Self::from_components(h)
+    }
+
+    /// The largest component, which has the sign of the whole expansion.
+    fn estimate(&self) -> f64 {
+        self.0.last().copied().unwrap_or(0.0)
+    }
+
+    /// The components summed from the smallest up, which unlike `estimate`
+    /// is close to the exact value.
+    fn value(&self) -> f64 {
+        self.0.iter().sum()
+    }
+}
+
+impl Add for &Expansion {
+    type Output = Expansion;
+
+    fn add(self, other: Self) -> Expansion {
+        other.0.iter().fold(self.clone(), |sum, &c| sum.grow(c))
+    }
+}
+
+impl Neg for &Expansion {
+    type Output = Expansion;
+
+    fn neg(self) -> Expansion {
+        Expansion(self.0.iter().map(|&c| -c).collect())
+    }
+}
+
+impl Sub for &Expansion {
+    type Output = Expansion;
+
+    fn sub(self, other: Self) -> Expansion {
+        self + &-other
+    }
+}
+
+impl Mul for &Expansion {
+    type Output = Expansion;
+
+    fn mul(self, other: Self) -> Expansion {
+        other.0.iter().fold(Expansion(Vec::new()), |product, &c| {
+            &product + &self.scale(c)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::Rng;
+
+    fn sign(x: f64) -> i32 {
+        if x > 0.0 {
+            1
+        } else if x < 0.0 {
+            -1
+        } else {
+            0
+        }
+    }
+
+    /// `x * 2^53` as an integer; exact for the grid points used below.
+    fn fixed(x: f64) -> i128 {
+        let scaled = x * (1u64 << 53) as f64;
+        assert_eq!(scaled.fract(), 0.0);
+        scaled as i128
+    }
+
+    #[test]
+    fn test_orient2d_near_collinear_grid() {
+        // Shewchuk's example: points within a few ulps of the line y = x.
+        let (b, c) = (Vector2::new(12.0, 12.0), Vector2::new(24.0, 24.0));
+        let ulp = f64::EPSILON / 2.0;
+        let mut naive_errors = 0;
+        for i in 0..64 {
+            for j in 0..64 {
+                let a = Vector2::new(0.5 + i as f64 * ulp, 0.5 + j as f64 * ulp);
+                let [ax, ay, bx, by, cx, cy] = [a.x, a.y, b.x, b.y, c.x, c.y].map(fixed);
+                let exact = ((ax - cx) * (by - cy) - (ay - cy) * (bx - cx)).signum() as i32;
+                assert_eq!(sign(orient2d(a, b, c)), exact, "{a:?}");
+                let naive = (a.x - c.x) * (b.y - c.y) - (a.y - c.y) * (b.x - c.x);
+                naive_errors += (sign(naive) != exact) as usize;
+            }
+        }
+        assert!(naive_errors > 0);
+    }
+
+    #[test]
+    fn test_triangle_normal_near_collinear_grid() {
+        let (b, c) = (Vector3::new(12.0, 12.0, 0.0), Vector3::new(24.0, 24.0, 0.0));
+        let ulp = f64::EPSILON / 2.0;
+        let mut naive_errors = 0;
+        for i in 0..64 {
+            for j in 0..64 {
+                let a = Vector3::new(0.5 + i as f64 * ulp, 0.5 + j as f64 * ulp, 0.0);
+                let [ax, ay, bx, by, cx, cy] = [a.x, a.y, b.x, b.y, c.x, c.y].map(fixed);
+                let exact =
+                    ((bx - ax) * (cy - ay) - (by - ay) * (cx - ax)) as f64 / (1u128 << 106) as f64;
+                let normal = triangle_normal(a, b, c);
+                assert_eq!((normal.x, normal.y), (0.0, 0.0));
+                assert!(
+                    (normal.z - exact).abs() <= 2.0 * ulp * exact.abs(),
+                    "{a:?} {normal:?} {exact:e}"
+                );
+                let naive = (b - a).cross(c - a).z;
+                naive_errors += (naive != exact) as usize;
+            }
+        }
+        assert!(naive_errors > 0);
+    }
+
+    #[test]
+    fn test_orient3d_near_coplanar() {
+        // Points exactly on the plane x + y + z = 0, with coordinates whose
+        // products round.
+        let mut rng = Rng::new(18);
+        let mut on_plane = || {
+            let grid = |rng: &mut Rng| {
+                (rng.range(-1.0, 1.0) * (1 << 30) as f64).round() / (1 << 30) as f64
+            };
+            let (x, y) = (grid(&mut rng), grid(&mut rng));
+            Vector3::new(x, y, -(x + y))
+        };
+        for _ in 0..1000 {
+            let (a, b, c, d) = (on_plane(), on_plane(), on_plane(), on_plane());
+            assert_eq!(orient3d(a, b, c, d), 0.0);
+            // The plane normal is parallel to (1, 1, 1), so its sign follows
+            // the orientation of the projection onto the xy-plane.
+            let up = sign(orient2d(a.truncate(), b.truncate(), c.truncate()));
+            let above = Vector3::new(d.x.next_up(), d.y, d.z);
+            let below = Vector3::new(d.x.next_down(), d.y, d.z);
+            assert_eq!(sign(orient3d(a, b, c, above)), -up);
+            assert_eq!(sign(orient3d(a, b, c, below)), up);
+        }
+    }
+
+    #[test]
+    fn test_incircle_cocircular() {
+        // Integer points on the circle of radius 5, scaled down and shifted
+        // to an offset with a long mantissa.
+        let circle = [
+            (5, 0),
+            (4, 3),
+            (3, 4),
+            (0, 5),
+            (-3, 4),
+            (-4, -3),
+            (0, -5),
+            (3, -4),
+        ];
+        let mut rng = Rng::new(19);
+        for _ in 0..200 {
+            let offset = Vector2::new(
+                (rng.range(0.0, 1024.0) * 2f64.powi(40)).round() / 2f64.powi(40),
+                (rng.range(0.0, 1024.0) * 2f64.powi(40)).round() / 2f64.powi(40),
+            );
+            let point =
+                |(x, y): (i32, i32)| offset + Vector2::new(x as f64, y as f64) * 2f64.powi(-30);
+            let (a, b, c) = (point(circle[0]), point(circle[2]), point(circle[4]));
+            for &p in &circle[5..] {
+                let d = point(p);
+                assert_eq!(incircle(a, b, c, d), 0.0);
+                // Moving a point of the lower half down takes it outside.
+                assert!(incircle(a, b, c, Vector2::new(d.x, d.y.next_down())) < 0.0);
+                assert!(incircle(a, b, c, Vector2::new(d.x, d.y.next_up())) > 0.0);
+            }
+        }
+    }
+
+    #[test]
+    fn test_insphere_cospherical() {
+        // Integer points on the sphere of radius 3.
+        let sphere = [
+            (3, 0, 0),
+            (0, 3, 0),
+            (0, 0, 3),
+            (-1, -2, 2),
+            (2, -2, -1),
+            (-2, 1, -2),
+            (-1, 2, -2),
+        ];
+        let mut rng = Rng::new(20);
+        for _ in 0..200 {
+            let offset = rng.vector(0.0, 1024.0) * 2f64.powi(40);
+            let offset =
+                Vector3::new(offset.x.round(), offset.y.round(), offset.z.round()) * 2f64.powi(-40);
+            let point = |(x, y, z): (i32, i32, i32)| {
+                offset + Vector3::new(x as f64, y as f64, z as f64) * 2f64.powi(-30)
+            };
+            let (a, b, c, d) = (
+                point(sphere[0]),
+                point(sphere[1]),
+                point(sphere[2]),
+                point(sphere[3]),
+            );
+            let (a, b) = if orient3d(a, b, c, d) > 0.0 {
+                (a, b)
+            } else {
+                (b, a)
+            };
+            assert!(orient3d(a, b, c, d) > 0.0);
+            for &p in &sphere[4..] {
+                let e = point(p);
+                assert_eq!(insphere(a, b, c, d, e), 0.0);
+                // All remaining points have negative z.
+                assert!(insphere(a, b, c, d, Vector3::new(e.x, e.y, e.z.next_down())) < 0.0);
+                assert!(insphere(a, b, c, d, Vector3::new(e.x, e.y, e.z.next_up())) > 0.0);
+            }
+            assert_eq!(insphere(a, b, c, d, a), 0.0);
+        }
+    }
+
+    #[test]
+    fn test_predicates_exact_path_agrees() {
+        let mut rng = Rng::new(21);
+        let v2 = |rng: &mut Rng| Vector2::new(rng.range(-1.0, 1.0), rng.range(-1.0, 1.0));
+        for _ in 0..1000 {
+            let [a, b, c, d] = [(); 4].map(|_| v2(&mut rng));
+            assert_eq!(sign(orient2d(a, b, c)), sign(orient2d_exact(a, b, c)));
+            assert_eq!(sign(incircle(a, b, c, d)), sign(incircle_exact(a, b, c, d)));
+
+            let [a, b, c, d, e] = [(); 5].map(|_| rng.vector(-1.0, 1.0));
+            assert_eq!(sign(orient3d(a, b, c, d)), sign(orient3d_exact(a, b, c, d)));
+            assert_eq!(
+                sign(insphere(a, b, c, d, e)),
+                sign(insphere_exact(a, b, c, d, e))
+            );
+        }
+    }
+
+    #[test]
+    fn test_predicates_signs() {
+        let [o, x, y] = [(0.0, 0.0), (1.0, 0.0), (0.0, 1.0)].map(|(a, b)| Vector2::new(a, b));
+        assert!(orient2d(o, x, y) > 0.0);
+        assert!(orient2d(o, y, x) < 0.0);
+        assert!(incircle(o, x, y, Vector2::new(0.5, 0.5)) > 0.0);
+        assert!(incircle(o, x, y, Vector2::new(2.0, 2.0)) < 0.0);
+
+        let z = Vector3::new(0.0, 0.0, 1.0);
+        let [o, x, y] = [o, x, y].map(|p| p.extend(0.0));
+        assert!(orient3d(o, x, y, -z) > 0.0);
+        assert!(orient3d(o, x, y, z) < 0.0);
+        assert!(insphere(o, x, y, -z, Vector3::new(0.1, 0.1, -0.1)) > 0.0);
+        assert!(insphere(o, x, y, -z, Vector3::new(2.0, 2.0, 2.0)) < 0.0);
+    }
+}
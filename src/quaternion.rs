@@ -0,0 +1,346 @@
+use std::ops::{Add, Mul, Neg, Sub};
+
+use crate::{Matrix3, UnitVector3, Vector3};
+
+/// A quaternion `w + xi + yj + zk`, stored as a vector part `v` and a scalar
+/// part `w`. Rotations are represented by unit quaternions.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[repr(C)]
+pub struct Quaternion {
+    pub v: Vector3,
+    pub w: f64,
+}
+
+impl Quaternion {
+    pub const IDENTITY: Self = Self {
+        v: Vector3 {
+            x: 0.0,
+            y: 0.0,
+            z: 0.0,
+        },
+        w: 1.0,
+    };
+
+    pub fn new(v: Vector3, w: f64) -> Self {
+        Self { v, w }
+    }
+
+    /// Rotation by `angle` radians around `axis`, which need not be normalized.
+    pub fn from_axis_angle(axis: Vector3, angle: f64) -> Self {
+        let (sin, cos) = (angle * 0.5).sin_cos();
+        Self::new(axis.normalized() * sin, cos)
+    }
+
+    /// Rotation by `angle` radians around an axis already known to be unit
+    /// length.
+    pub fn from_unit_axis_angle(axis: UnitVector3, angle: f64) -> Self {
+        let (sin, cos) = (angle * 0.5).sin_cos();
+        Self::new(*axis * sin, cos)
+    }
+
+    /// The shortest rotation taking the direction of `from` to the direction
+    /// of `to`.
+    pub fn from_rotation_arc(from: Vector3, to: Vector3) -> Self {
+        let from = from.normalized();
+        let to = to.normalized();
+        let d = from.dot(to);
+        if d < -1.0 + 1e-12 {
+            // Antiparallel: rotate half a turn around any perpendicular axis.
+            let mut axis = Vector3::new(1.0, 0.0, 0.0).cross(from);
+            if axis.dot(axis) < 1e-12 {
+                axis = Vector3::new(0.0, 1.0, 0.0).cross(from);
+            }
+            return Self::new(axis.normalized(), 0.0);
+        }
+        Self::new(from.cross(to), 1.0 + d).normalized()
+    }
+
+    /// Builds a quaternion from a pure rotation matrix.
+    pub fn from_matrix3(m: Matrix3) -> Self {
+        let [c0, c1, c2] = m.cols;
+        let (m00, m11, m22) = (c0.x, c1.y, c2.z);
+        let trace = m00 + m11 + m22;
+        // Pick the largest of w, x, y, z to divide by for numerical stability.
+        let q = if trace > 0.0 {
+            let s = (trace + 1.0).sqrt() * 2.0;
+            Self::new(
+                Vector3::new((c1.z - c2.y) / s, (c2.x - c0.z) / s, (c0.y - c1.x) / s),
+                0.25 * s,
+            )
+        } else if m00 > m11 && m00 > m22 {
+            let s = (1.0 + m00 - m11 - m22).sqrt() * 2.0;
+            Self::new(
+                Vector3::new(0.25 * s, (c1.x + c0.y) / s, (c2.x + c0.z) / s),
+                (c1.z - c2.y) / s,
+            )
+        } else if m11 > m22 {
+            let s = (1.0 + m11 - m00 - m22).sqrt() * 2.0;
+            Self::new(
+                Vector3::new((c1.x + c0.y) / s, 0.25 * s, (c2.y + c1.z) / s),
+                (c2.x - c0.z) / s,
+            )
+        } else {
+            let s = (1.0 + m22 - m00 - m11).sqrt() * 2.0;
+            Self::new(
+                Vector3::new((c2.x + c0.z) / s, (c2.y + c1.z) / s, 0.25 * s),
+                (c0.y - c1.x) / s,
+            )
+        };
+        q.normalized()
+    }
+
+    /// The rotation matrix of a unit quaternion.
+    pub fn to_matrix3(self) -> Matrix3 {
+        Matrix3::from_cols(
+            self.rotate(Vector3::new(1.0, 0.0, 0.0)),
+            self.rotate(Vector3::new(0.0, 1.0, 0.0)),
+            self.rotate(Vector3::new(0.0, 0.0, 1.0)),
+        )
+    }
+
+    pub fn dot(self, other: Self) -> f64 {
+        self.v.dot(other.v) + self.w * other.w
+    }
+
+    pub fn length(self) -> f64 {
+        self.dot(self).sqrt()
+    }
+
+    pub fn normalized(self) -> Self {
+        self * (1.0 / self.length())
+    }
+
+    pub fn conjugate(self) -> Self {
+        Self::new(-self.v, self.w)
+    }
+
+    /// Returns `None` for the zero quaternion.
+    pub fn inverse(self) -> Option<Self> {
+        let norm_squared = self.dot(self);
+        if norm_squared == 0.0 {
+            return None;
+        }
+        Some(self.conjugate() * (1.0 / norm_squared))
+    }
+
+    /// Rotates `v` by this quaternion, which must be normalized.
+    pub fn rotate(self, v: Vector3) -> Vector3 {
+        let t = 2.0 * self.v.cross(v);
+        v + self.w * t + self.v.cross(t)
+    }
+
+    /// Normalized linear interpolation along the shortest path.
+    pub fn nlerp(self, other: Self, t: f64) -> Self {
+        let other = if self.dot(other) < 0.0 { -other } else { other };
+        (self * (1.0 - t) + other * t).normalized()
+    }
+
+    /// Spherical linear interpolation along the shortest path.
+    pub fn slerp(self, other: Self, t: f64) -> Self {
+        let mut d = self.dot(other);
+        let other = if d < 0.0 {
+            d = -d;
+            -other
+        } else {
+            other
+        };
+        // Nearly identical rotations: fall back to nlerp to avoid dividing by
+        // a vanishing sine.
+        if d > 1.0 - 1e-9 {
+            return self.nlerp(other, t);
+        }
+        let theta = d.acos();
+        let sin_theta = theta.sin();
+        let a = ((1.0 - t) * theta).sin() / sin_theta;
+        let b = (t * theta).sin() / sin_theta;
+        self * a + other * b
+    }
+}
+
+impl Add for Quaternion {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.v + other.v, self.w + other.w)
+    }
+}
+
+impl Sub for Quaternion {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.v - other.v, self.w - other.w)
+    }
+}
+
+impl Neg for Quaternion {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.v, -self.w)
+    }
+}
+
+impl Mul<f64> for Quaternion {
+    type Output = Self;
+
+    fn mul(self, other: f64) -> Self {
+        Self::new(self.v * other, self.w * other)
+    }
+}
+
+/// The Hamilton product.
+impl Mul for Quaternion {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        Self::new(
+            self.w * other.v + other.w * self.v + self.v.cross(other.v),
+            self.w * other.w - self.v.dot(other.v),
+        )
+    }
+}
+
+impl Mul<Vector3> for Quaternion {
+    type Output = Vector3;
+
+    fn mul(self, other: Vector3) -> Vector3 {
+        self.rotate(other)
+    }
+}
+
+impl From<Quaternion> for Matrix3 {
+    fn from(q: Quaternion) -> Self {
+        q.to_matrix3()
+    }
+}
+
+impl From<Matrix3> for Quaternion {
+    fn from(m: Matrix3) -> Self {
+        Self::from_matrix3(m)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f64::consts::{FRAC_PI_2, PI};
+
+    fn assert_rotation_close(a: Quaternion, b: Quaternion) {
+        // q and -q represent the same rotation.
+        assert!(a.dot(b).abs() > 1.0 - 1e-12, "{a:?} != {b:?}");
+    }
+
+    #[test]
+    fn test_quaternion_from_axis_angle() {
+        let q = Quaternion::from_axis_angle(Vector3::new(0.0, 0.0, 2.0), FRAC_PI_2);
+        assert_vec_approx_eq!(
+            q.rotate(Vector3::new(1.0, 0.0, 0.0)),
+            Vector3::new(0.0, 1.0, 0.0),
+            epsilon = 1e-12
+        );
+        assert_vec_approx_eq!(
+            q * Vector3::new(0.0, 1.0, 0.0),
+            Vector3::new(-1.0, 0.0, 0.0),
+            epsilon = 1e-12
+        );
+        assert!((q.length() - 1.0).abs() < 1e-15);
+    }
+
+    #[test]
+    fn test_quaternion_from_unit_axis_angle() {
+        let axis = Vector3::new(1.0, -1.0, 2.0);
+        assert_eq!(
+            Quaternion::from_unit_axis_angle(UnitVector3::try_new(axis).unwrap(), 0.5),
+            Quaternion::from_axis_angle(axis, 0.5)
+        );
+    }
+
+    #[test]
+    fn test_quaternion_hamilton_product() {
+        let i = Quaternion::new(Vector3::new(1.0, 0.0, 0.0), 0.0);
+        let j = Quaternion::new(Vector3::new(0.0, 1.0, 0.0), 0.0);
+        let k = Quaternion::new(Vector3::new(0.0, 0.0, 1.0), 0.0);
+        assert_eq!(i * j, k);
+        assert_eq!(j * i, -k);
+        assert_eq!(i * i, Quaternion::new(Vector3::ZERO, -1.0));
+        assert_eq!(i * j * k, Quaternion::new(Vector3::ZERO, -1.0));
+    }
+
+    #[test]
+    fn test_quaternion_composition() {
+        let a = Quaternion::from_axis_angle(Vector3::new(1.0, 0.0, 0.0), 0.3);
+        let b = Quaternion::from_axis_angle(Vector3::new(0.0, 1.0, 1.0), 1.1);
+        let v = Vector3::new(0.5, -2.0, 1.5);
+        assert_vec_approx_eq!((a * b).rotate(v), a.rotate(b.rotate(v)), epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_quaternion_inverse() {
+        let q = Quaternion::from_axis_angle(Vector3::new(1.0, 2.0, 3.0), 0.7);
+        assert_rotation_close(q * q.inverse().unwrap(), Quaternion::IDENTITY);
+        assert_eq!(q.inverse().unwrap(), q.conjugate());
+
+        let scaled = q * 2.0;
+        assert_rotation_close(scaled * scaled.inverse().unwrap(), Quaternion::IDENTITY);
+        assert_eq!(Quaternion::new(Vector3::ZERO, 0.0).inverse(), None);
+    }
+
+    #[test]
+    fn test_quaternion_from_rotation_arc() {
+        let from = Vector3::new(1.0, 2.0, 3.0);
+        let to = Vector3::new(-2.0, 0.5, 1.0);
+        let q = Quaternion::from_rotation_arc(from, to);
+        assert_vec_approx_eq!(
+            q.rotate(from.normalized()),
+            to.normalized(),
+            epsilon = 1e-12
+        );
+
+        let q = Quaternion::from_rotation_arc(from, -from);
+        assert_vec_approx_eq!(q.rotate(from), -from, epsilon = 1e-12);
+
+        let q = Quaternion::from_rotation_arc(from, from);
+        assert_rotation_close(q, Quaternion::IDENTITY);
+    }
+
+    #[test]
+    fn test_quaternion_matrix_round_trip() {
+        let axes = [
+            Vector3::new(1.0, 0.0, 0.0),
+            Vector3::new(0.0, 1.0, 0.0),
+            Vector3::new(0.0, 0.0, 1.0),
+            Vector3::new(1.0, -2.0, 0.5),
+        ];
+        for axis in axes {
+            for angle in [0.0, 0.4, 2.0, PI, 4.0] {
+                let q = Quaternion::from_axis_angle(axis, angle);
+                let m = Matrix3::from(q);
+                let v = Vector3::new(0.3, 0.2, -0.9);
+                assert_vec_approx_eq!(m * v, q.rotate(v), epsilon = 1e-12);
+                assert_rotation_close(Quaternion::from(m), q);
+            }
+        }
+    }
+
+    #[test]
+    fn test_quaternion_slerp() {
+        let axis = Vector3::new(0.0, 1.0, 0.0);
+        let a = Quaternion::from_axis_angle(axis, 0.2);
+        let b = Quaternion::from_axis_angle(axis, 1.4);
+        assert_rotation_close(a.slerp(b, 0.0), a);
+        assert_rotation_close(a.slerp(b, 1.0), b);
+        assert_rotation_close(a.slerp(b, 0.25), Quaternion::from_axis_angle(axis, 0.5));
+        // Takes the short way round even when b is given as -b.
+        assert_rotation_close(a.slerp(-b, 0.5), Quaternion::from_axis_angle(axis, 0.8));
+    }
+
+    #[test]
+    fn test_quaternion_nlerp() {
+        let axis = Vector3::new(0.0, 0.0, 1.0);
+        let a = Quaternion::from_axis_angle(axis, 0.0);
+        let b = Quaternion::from_axis_angle(axis, 1.0);
+        assert_rotation_close(a.nlerp(b, 0.5), Quaternion::from_axis_angle(axis, 0.5));
+        assert!((a.nlerp(b, 0.3).length() - 1.0).abs() < 1e-15);
+    }
+}
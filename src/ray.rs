@@ -0,0 +1,51 @@
+use crate::{UnitVector3, Vector3};
+
+/// A half-line starting at `origin`. Because `direction` has unit length,
+/// hit distances are measured in world units.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Ray {
+    pub origin: Vector3,
+    pub direction: UnitVector3,
+}
+
+/// Where a ray meets a surface.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RayHit {
+    /// Distance along the ray; never negative.
+    pub distance: f64,
+    pub point: Vector3,
+    /// The surface normal at `point`. Closed shapes report the outward
+    /// normal; planes and triangles report the side facing the ray origin.
+    pub normal: UnitVector3,
+}
+
+impl Ray {
+    pub fn new(origin: Vector3, direction: UnitVector3) -> Self {
+        Self { origin, direction }
+    }
+
+    /// The point at distance `t` along the ray.
+    pub fn at(self, t: f64) -> Vector3 {
+        self.origin + *self.direction * t
+    }
+
+    pub(crate) fn hit(self, distance: f64, normal: UnitVector3) -> RayHit {
+        RayHit {
+            distance,
+            point: self.at(distance),
+            normal,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ray_at() {
+        let ray = Ray::new(Vector3::new(1.0, 2.0, 3.0), UnitVector3::Y);
+        assert_eq!(ray.at(0.0), Vector3::new(1.0, 2.0, 3.0));
+        assert_eq!(ray.at(2.5), Vector3::new(1.0, 4.5, 3.0));
+    }
+}
@@ -0,0 +1,177 @@
+use std::fmt::Debug;
+use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
+
+/// Component type of the vector types.
+///
+/// Implemented for `f32`, `f64` and the signed integer types.
+pub trait Scalar:
+    Copy
+    + Debug
+    + PartialEq
+    + PartialOrd
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Rem<Output = Self>
+    + Neg<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    // Kernels behind the `Vector3` arithmetic. The `simd` feature overrides
+    // them for `f32` and `f64`; the defaults are the portable scalar path.
+
+    #[doc(hidden)]
+    fn add3(a: [Self; 3], b: [Self; 3]) -> [Self; 3] {
+        scalar3::add3(a, b)
+    }
+
+    #[doc(hidden)]
+    fn sub3(a: [Self; 3], b: [Self; 3]) -> [Self; 3] {
+        scalar3::sub3(a, b)
+    }
+
+    #[doc(hidden)]
+    fn mul3(a: [Self; 3], b: [Self; 3]) -> [Self; 3] {
+        scalar3::mul3(a, b)
+    }
+
+    #[doc(hidden)]
+    fn dot3(a: [Self; 3], b: [Self; 3]) -> Self {
+        scalar3::dot3(a, b)
+    }
+
+    #[doc(hidden)]
+    fn cross3(a: [Self; 3], b: [Self; 3]) -> [Self; 3] {
+        scalar3::cross3(a, b)
+    }
+}
+
+/// Floating-point scalars, which additionally support division and square roots.
+pub trait Float: Scalar + Div<Output = Self> {
+    /// The difference between `1` and the next larger representable value.
+    const EPSILON: Self;
+
+    fn sqrt(self) -> Self;
+    fn abs(self) -> Self;
+    fn is_finite(self) -> bool;
+    fn acos(self) -> Self;
+
+    #[doc(hidden)]
+    fn div3(a: [Self; 3], b: [Self; 3]) -> [Self; 3] {
+        scalar3::div3(a, b)
+    }
+}
+
+/// The portable implementation of the `Vector3` kernels.
+pub(crate) mod scalar3 {
+    use super::{Float, Scalar};
+
+    pub fn add3<T: Scalar>(a: [T; 3], b: [T; 3]) -> [T; 3] {
+        [a[0] + b[0], a[1] + b[1], a[2] + b[2]]
+    }
+
+    pub fn sub3<T: Scalar>(a: [T; 3], b: [T; 3]) -> [T; 3] {
+        [a[0] - b[0], a[1] - b[1], a[2] - b[2]]
+    }
+
+    pub fn mul3<T: Scalar>(a: [T; 3], b: [T; 3]) -> [T; 3] {
+        [a[0] * b[0], a[1] * b[1], a[2] * b[2]]
+    }
+
+    pub fn div3<T: Float>(a: [T; 3], b: [T; 3]) -> [T; 3] {
+        [a[0] / b[0], a[1] / b[1], a[2] / b[2]]
+    }
+
+    pub fn dot3<T: Scalar>(a: [T; 3], b: [T; 3]) -> T {
+        a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+    }
+
+    pub fn cross3<T: Scalar>(a: [T; 3], b: [T; 3]) -> [T; 3] {
+        [
+            a[1] * b[2] - a[2] * b[1],
+            a[2] * b[0] - a[0] * b[2],
+            a[0] * b[1] - a[1] * b[0],
+        ]
+    }
+}
+
+macro_rules! impl_scalar {
+    ($zero:expr, $one:expr; $($t:ty),*) => {
+        $(
+            impl Scalar for $t {
+                const ZERO: Self = $zero;
+                const ONE: Self = $one;
+            }
+        )*
+    };
+    ($zero:expr, $one:expr; $($t:ty => $simd:ident),*) => {
+        $(
+            impl Scalar for $t {
+                const ZERO: Self = $zero;
+                const ONE: Self = $one;
+
+                #[cfg(all(feature = "simd", target_arch = "x86_64"))]
+                fn add3(a: [Self; 3], b: [Self; 3]) -> [Self; 3] {
+                    crate::simd::$simd::add3(a, b)
+                }
+
+                #[cfg(all(feature = "simd", target_arch = "x86_64"))]
+                fn sub3(a: [Self; 3], b: [Self; 3]) -> [Self; 3] {
+                    crate::simd::$simd::sub3(a, b)
+                }
+
+                #[cfg(all(feature = "simd", target_arch = "x86_64"))]
+                fn mul3(a: [Self; 3], b: [Self; 3]) -> [Self; 3] {
+                    crate::simd::$simd::mul3(a, b)
+                }
+
+                #[cfg(all(feature = "simd", target_arch = "x86_64"))]
+                fn dot3(a: [Self; 3], b: [Self; 3]) -> Self {
+                    crate::simd::$simd::dot3(a, b)
+                }
+
+                #[cfg(all(feature = "simd", target_arch = "x86_64"))]
+                fn cross3(a: [Self; 3], b: [Self; 3]) -> [Self; 3] {
+                    crate::simd::$simd::cross3(a, b)
+                }
+            }
+        )*
+    };
+}
+
+impl_scalar!(0.0, 1.0; f32 => f32x3, f64 => f64x3);
+impl_scalar!(0, 1; i8, i16, i32, i64, i128, isize);
+
+macro_rules! impl_float {
+    ($($t:ty => $simd:ident),*) => {
+        $(
+            impl Float for $t {
+                const EPSILON: Self = <$t>::EPSILON;
+
+                fn sqrt(self) -> Self {
+                    <$t>::sqrt(self)
+                }
+
+                fn abs(self) -> Self {
+                    <$t>::abs(self)
+                }
+
+                fn is_finite(self) -> bool {
+                    <$t>::is_finite(self)
+                }
+
+                fn acos(self) -> Self {
+                    <$t>::acos(self)
+                }
+
+                #[cfg(all(feature = "simd", target_arch = "x86_64"))]
+                fn div3(a: [Self; 3], b: [Self; 3]) -> [Self; 3] {
+                    crate::simd::$simd::div3(a, b)
+                }
+            }
+        )*
+    };
+}
+
+impl_float!(f32 => f32x3, f64 => f64x3);
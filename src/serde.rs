@@ -0,0 +1,255 @@
+//! Serde support, enabled by the `serde` feature.
+//!
+//! Vectors and points serialize as compact sequences such as `[x, y, z]`.
+//! Matrices serialize as a sequence of columns and quaternions as
+//! `[x, y, z, w]`. Use [`as_map`] to write a `Vector3` as `{x, y, z}` instead.
+//!
+//! Non-finite components are handed to the format unchanged. Formats that
+//! can't represent them, such as JSON (which writes `null`), fail to read them
+//! back rather than silently substituting a value.
+
+use std::fmt;
+use std::marker::PhantomData;
+
+use ::serde::de::{self, Deserialize, Deserializer, SeqAccess, Visitor};
+use ::serde::ser::{Serialize, SerializeTuple, Serializer};
+
+use crate::{Matrix3, Matrix4, Point3, Quaternion, Vector2, Vector3, Vector4};
+
+macro_rules! impl_serde_seq {
+    ($($type:ident { $($field:ident),+ } = $len:literal),*) => {
+        $(
+            impl<T: Serialize> Serialize for $type<T> {
+                fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    let mut tuple = serializer.serialize_tuple($len)?;
+                    $(tuple.serialize_element(&self.$field)?;)+
+                    tuple.end()
+                }
+            }
+
+            impl<'de, T: Deserialize<'de>> Deserialize<'de> for $type<T> {
+                fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    let [$($field),+] = deserializer.deserialize_tuple(
+                        $len,
+                        ArrayVisitor::<T, $len>(concat!(stringify!($type), " components"), PhantomData),
+                    )?;
+                    Ok(Self { $($field),+ })
+                }
+            }
+        )*
+    };
+}
+
+impl_serde_seq!(
+    Vector2 { x, y } = 2,
+    Vector3 { x, y, z } = 3,
+    Vector4 { x, y, z, w } = 4,
+    Point3 { x, y, z } = 3
+);
+
+/// Reads exactly `N` elements, rejecting shorter and longer sequences.
+struct ArrayVisitor<T, const N: usize>(&'static str, PhantomData<T>);
+
+impl<'de, T: Deserialize<'de>, const N: usize> Visitor<'de> for ArrayVisitor<T, N> {
+    type Value = [T; N];
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "an array of {N} {}", self.0)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<[T; N], A::Error> {
+        let mut elements = [const { None }; N];
+        for (len, element) in elements.iter_mut().enumerate() {
+            match seq.next_element()? {
+                Some(value) => *element = Some(value),
+                None => return Err(de::Error::invalid_length(len, &self)),
+            }
+        }
+        // Count the rest so the error reports the actual length.
+        let mut len = N;
+        while seq.next_element::<de::IgnoredAny>()?.is_some() {
+            len += 1;
+        }
+        if len != N {
+            return Err(de::Error::invalid_length(len, &self));
+        }
+        Ok(elements.map(|element| element.expect("every element was filled")))
+    }
+}
+
+impl<T: Serialize> Serialize for Matrix3<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.cols.serialize(serializer)
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Matrix3<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let cols = deserializer
+            .deserialize_tuple(3, ArrayVisitor::<_, 3>("Matrix3 columns", PhantomData))?;
+        Ok(Self { cols })
+    }
+}
+
+impl<T: Serialize> Serialize for Matrix4<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.cols.serialize(serializer)
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Matrix4<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let cols = deserializer
+            .deserialize_tuple(4, ArrayVisitor::<_, 4>("Matrix4 columns", PhantomData))?;
+        Ok(Self { cols })
+    }
+}
+
+impl Serialize for Quaternion {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.v.extend(self.w).serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Quaternion {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let [x, y, z, w] = deserializer.deserialize_tuple(
+            4,
+            ArrayVisitor::<f64, 4>("Quaternion components", PhantomData),
+        )?;
+        Ok(Self::new(Vector3::new(x, y, z), w))
+    }
+}
+
+/// Serializes a `Vector3` as a `{x, y, z}` map, for use with
+/// `#[serde(with = "vector3::serde::as_map")]`.
+pub mod as_map {
+    use ::serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use crate::Vector3;
+
+    #[derive(Serialize)]
+    struct MapRef<'a, T> {
+        x: &'a T,
+        y: &'a T,
+        z: &'a T,
+    }
+
+    #[derive(Deserialize)]
+    #[serde(deny_unknown_fields, rename = "Vector3")]
+    struct Map<T> {
+        x: T,
+        y: T,
+        z: T,
+    }
+
+    pub fn serialize<T: Serialize, S: Serializer>(
+        v: &Vector3<T>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        MapRef {
+            x: &v.x,
+            y: &v.y,
+            z: &v.z,
+        }
+        .serialize(serializer)
+    }
+
+    pub fn deserialize<'de, T: Deserialize<'de>, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Vector3<T>, D::Error> {
+        let Map { x, y, z } = Map::deserialize(deserializer)?;
+        Ok(Vector3 { x, y, z })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ::serde::{Deserialize, Serialize};
+
+    #[test]
+    fn test_serde_vector_seq() {
+        let v = Vector3::new(1.0, -2.5, 3.0);
+        let json = serde_json::to_string(&v).unwrap();
+        assert_eq!(json, "[1.0,-2.5,3.0]");
+        assert_eq!(serde_json::from_str::<Vector3>(&json).unwrap(), v);
+
+        let v = Vector2::new(1, 2);
+        assert_eq!(serde_json::to_string(&v).unwrap(), "[1,2]");
+        let v = Vector4::new(1, 2, 3, 4);
+        assert_eq!(
+            serde_json::from_str::<Vector4<i32>>("[1,2,3,4]").unwrap(),
+            v
+        );
+        let p = Point3::new(1, 2, 3);
+        assert_eq!(serde_json::from_str::<Point3<i32>>("[1,2,3]").unwrap(), p);
+    }
+
+    #[test]
+    fn test_serde_vector_wrong_length() {
+        let err = serde_json::from_str::<Vector3>("[1.0,2.0]").unwrap_err();
+        assert!(
+            err.to_string()
+                .starts_with("invalid length 2, expected an array of 3 Vector3 components"),
+            "{err}"
+        );
+        let err = serde_json::from_str::<Vector3>("[]").unwrap_err();
+        assert!(err.to_string().starts_with("invalid length 0"), "{err}");
+        let err = serde_json::from_str::<Vector3>("[1.0,2.0,3.0,4.0,5.0]").unwrap_err();
+        assert!(err.to_string().starts_with("invalid length 5"), "{err}");
+        assert!(serde_json::from_str::<Vector3>("{\"x\":1.0,\"y\":2.0,\"z\":3.0}").is_err());
+    }
+
+    #[test]
+    fn test_serde_vector_as_map() {
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Body {
+            #[serde(with = "crate::serde::as_map")]
+            position: Vector3,
+            velocity: Vector3,
+        }
+
+        let body = Body {
+            position: Vector3::new(1.0, 2.0, 3.0),
+            velocity: Vector3::new(0.0, 0.5, 0.0),
+        };
+        let json = serde_json::to_string(&body).unwrap();
+        assert_eq!(
+            json,
+            r#"{"position":{"x":1.0,"y":2.0,"z":3.0},"velocity":[0.0,0.5,0.0]}"#
+        );
+        assert_eq!(serde_json::from_str::<Body>(&json).unwrap(), body);
+
+        let missing = r#"{"position":{"x":1.0,"y":2.0},"velocity":[0.0,0.5,0.0]}"#;
+        assert!(serde_json::from_str::<Body>(missing).is_err());
+        let unknown = r#"{"position":{"x":1.0,"y":2.0,"z":3.0,"w":4.0},"velocity":[0.0,0.5,0.0]}"#;
+        assert!(serde_json::from_str::<Body>(unknown).is_err());
+    }
+
+    #[test]
+    fn test_serde_non_finite() {
+        let v = Vector3::new(f64::NAN, f64::INFINITY, 1.0);
+        let json = serde_json::to_string(&v).unwrap();
+        assert_eq!(json, "[null,null,1.0]");
+        assert!(serde_json::from_str::<Vector3>(&json).is_err());
+    }
+
+    #[test]
+    fn test_serde_matrix_quaternion() {
+        let m = Matrix3::from_diagonal(Vector3::new(1.0, 2.0, 3.0));
+        let json = serde_json::to_string(&m).unwrap();
+        assert_eq!(json, "[[1.0,0.0,0.0],[0.0,2.0,0.0],[0.0,0.0,3.0]]");
+        assert_eq!(serde_json::from_str::<Matrix3>(&json).unwrap(), m);
+
+        let m = Matrix4::from_translation(Vector3::new(1.0, 2.0, 3.0));
+        let json = serde_json::to_string(&m).unwrap();
+        assert_eq!(serde_json::from_str::<Matrix4>(&json).unwrap(), m);
+        assert!(serde_json::from_str::<Matrix4>("[[1.0,0.0,0.0,0.0]]").is_err());
+
+        let q = Quaternion::new(Vector3::new(0.0, 0.0, 0.6), 0.8);
+        let json = serde_json::to_string(&q).unwrap();
+        assert_eq!(json, "[0.0,0.0,0.6,0.8]");
+        assert_eq!(serde_json::from_str::<Quaternion>(&json).unwrap(), q);
+    }
+}
@@ -0,0 +1,287 @@
+//! SIMD kernels for `Vector3<f32>` and `Vector3<f64>`, enabled by the `simd`
+//! feature on x86_64.
+//!
+//! `f64` vectors use AVX when the crate is compiled with the `avx` target
+//! feature and SSE2 otherwise; `f32` vectors always fit in one SSE register.
+//! The kernels perform the same IEEE operations in the same order as the
+//! scalar path in `scalar::scalar3`, and never fuse multiplies and adds, so
+//! their results are bit-identical to it.
+//!
+//! The intrinsics are only `unsafe` because they require target features.
+//! SSE and SSE2 are part of the x86_64 baseline, and the AVX path is only
+//! compiled when AVX is enabled for the whole build, so every call is sound.
+
+pub(crate) mod f64x3 {
+    pub use imp::*;
+
+    #[cfg(not(target_feature = "avx"))]
+    mod imp {
+        use std::arch::x86_64::*;
+
+        // x and y share one register, z sits in the low lane of another.
+        type V = (__m128d, __m128d);
+
+        fn load(a: [f64; 3]) -> V {
+            unsafe { (_mm_set_pd(a[1], a[0]), _mm_set_sd(a[2])) }
+        }
+
+        fn store((xy, z): V) -> [f64; 3] {
+            unsafe {
+                [
+                    _mm_cvtsd_f64(xy),
+                    _mm_cvtsd_f64(_mm_unpackhi_pd(xy, xy)),
+                    _mm_cvtsd_f64(z),
+                ]
+            }
+        }
+
+        pub fn add3(a: [f64; 3], b: [f64; 3]) -> [f64; 3] {
+            unsafe {
+                let (a, b) = (load(a), load(b));
+                store((_mm_add_pd(a.0, b.0), _mm_add_sd(a.1, b.1)))
+            }
+        }
+
+        pub fn sub3(a: [f64; 3], b: [f64; 3]) -> [f64; 3] {
+            unsafe {
+                let (a, b) = (load(a), load(b));
+                store((_mm_sub_pd(a.0, b.0), _mm_sub_sd(a.1, b.1)))
+            }
+        }
+
+        pub fn mul3(a: [f64; 3], b: [f64; 3]) -> [f64; 3] {
+            unsafe {
+                let (a, b) = (load(a), load(b));
+                store((_mm_mul_pd(a.0, b.0), _mm_mul_sd(a.1, b.1)))
+            }
+        }
+
+        pub fn div3(a: [f64; 3], b: [f64; 3]) -> [f64; 3] {
+            unsafe {
+                let (a, b) = (load(a), load(b));
+                store((_mm_div_pd(a.0, b.0), _mm_div_sd(a.1, b.1)))
+            }
+        }
+
+        pub fn dot3(a: [f64; 3], b: [f64; 3]) -> f64 {
+            unsafe {
+                let (a, b) = (load(a), load(b));
+                let xy = _mm_mul_pd(a.0, b.0);
+                let z = _mm_mul_sd(a.1, b.1);
+                let sum = _mm_add_sd(xy, _mm_unpackhi_pd(xy, xy));
+                _mm_cvtsd_f64(_mm_add_sd(sum, z))
+            }
+        }
+
+        pub fn cross3(a: [f64; 3], b: [f64; 3]) -> [f64; 3] {
+            unsafe {
+                let xy = _mm_sub_pd(
+                    _mm_mul_pd(_mm_set_pd(a[2], a[1]), _mm_set_pd(b[0], b[2])),
+                    _mm_mul_pd(_mm_set_pd(a[0], a[2]), _mm_set_pd(b[2], b[1])),
+                );
+                let z = _mm_sub_sd(
+                    _mm_mul_sd(_mm_set_sd(a[0]), _mm_set_sd(b[1])),
+                    _mm_mul_sd(_mm_set_sd(a[1]), _mm_set_sd(b[0])),
+                );
+                store((xy, z))
+            }
+        }
+    }
+
+    #[cfg(target_feature = "avx")]
+    mod imp {
+        use std::arch::x86_64::*;
+
+        // Lanes are x, y, z and an unused zero.
+        fn load(a: [f64; 3]) -> __m256d {
+            unsafe { _mm256_set_pd(0.0, a[2], a[1], a[0]) }
+        }
+
+        fn store(v: __m256d) -> [f64; 3] {
+            unsafe {
+                let xy = _mm256_castpd256_pd128(v);
+                let zw = _mm256_extractf128_pd::<1>(v);
+                [
+                    _mm_cvtsd_f64(xy),
+                    _mm_cvtsd_f64(_mm_unpackhi_pd(xy, xy)),
+                    _mm_cvtsd_f64(zw),
+                ]
+            }
+        }
+
+        pub fn add3(a: [f64; 3], b: [f64; 3]) -> [f64; 3] {
+            unsafe { store(_mm256_add_pd(load(a), load(b))) }
+        }
+
+        pub fn sub3(a: [f64; 3], b: [f64; 3]) -> [f64; 3] {
+            unsafe { store(_mm256_sub_pd(load(a), load(b))) }
+        }
+
+        pub fn mul3(a: [f64; 3], b: [f64; 3]) -> [f64; 3] {
+            unsafe { store(_mm256_mul_pd(load(a), load(b))) }
+        }
+
+        pub fn div3(a: [f64; 3], b: [f64; 3]) -> [f64; 3] {
+            unsafe { store(_mm256_div_pd(load(a), load(b))) }
+        }
+
+        pub fn dot3(a: [f64; 3], b: [f64; 3]) -> f64 {
+            unsafe {
+                let p = _mm256_mul_pd(load(a), load(b));
+                let xy = _mm256_castpd256_pd128(p);
+                let zw = _mm256_extractf128_pd::<1>(p);
+                let sum = _mm_add_sd(xy, _mm_unpackhi_pd(xy, xy));
+                _mm_cvtsd_f64(_mm_add_sd(sum, zw))
+            }
+        }
+
+        pub fn cross3(a: [f64; 3], b: [f64; 3]) -> [f64; 3] {
+            unsafe {
+                let yzx = |v: [f64; 3]| _mm256_set_pd(0.0, v[0], v[2], v[1]);
+                let zxy = |v: [f64; 3]| _mm256_set_pd(0.0, v[1], v[0], v[2]);
+                store(_mm256_sub_pd(
+                    _mm256_mul_pd(yzx(a), zxy(b)),
+                    _mm256_mul_pd(zxy(a), yzx(b)),
+                ))
+            }
+        }
+    }
+}
+
+pub(crate) mod f32x3 {
+    use std::arch::x86_64::*;
+
+    // Lane selectors for `_mm_shuffle_ps`: (y, z, x, w) and (z, x, y, w).
+    const YZX: i32 = 0b11_00_10_01;
+    const ZXY: i32 = 0b11_01_00_10;
+
+    // Lanes are x, y, z and an unused zero.
+    fn load(a: [f32; 3]) -> __m128 {
+        unsafe { _mm_set_ps(0.0, a[2], a[1], a[0]) }
+    }
+
+    fn store(v: __m128) -> [f32; 3] {
+        unsafe {
+            [
+                _mm_cvtss_f32(v),
+                _mm_cvtss_f32(_mm_shuffle_ps::<0b01_01_01_01>(v, v)),
+                _mm_cvtss_f32(_mm_movehl_ps(v, v)),
+            ]
+        }
+    }
+
+    pub fn add3(a: [f32; 3], b: [f32; 3]) -> [f32; 3] {
+        unsafe { store(_mm_add_ps(load(a), load(b))) }
+    }
+
+    pub fn sub3(a: [f32; 3], b: [f32; 3]) -> [f32; 3] {
+        unsafe { store(_mm_sub_ps(load(a), load(b))) }
+    }
+
+    pub fn mul3(a: [f32; 3], b: [f32; 3]) -> [f32; 3] {
+        unsafe { store(_mm_mul_ps(load(a), load(b))) }
+    }
+
+    pub fn div3(a: [f32; 3], b: [f32; 3]) -> [f32; 3] {
+        unsafe { store(_mm_div_ps(load(a), load(b))) }
+    }
+
+    pub fn dot3(a: [f32; 3], b: [f32; 3]) -> f32 {
+        unsafe {
+            let p = _mm_mul_ps(load(a), load(b));
+            let sum = _mm_add_ss(p, _mm_shuffle_ps::<0b01_01_01_01>(p, p));
+            _mm_cvtss_f32(_mm_add_ss(sum, _mm_movehl_ps(p, p)))
+        }
+    }
+
+    pub fn cross3(a: [f32; 3], b: [f32; 3]) -> [f32; 3] {
+        unsafe {
+            let (a, b) = (load(a), load(b));
+            store(_mm_sub_ps(
+                _mm_mul_ps(_mm_shuffle_ps::<YZX>(a, a), _mm_shuffle_ps::<ZXY>(b, b)),
+                _mm_mul_ps(_mm_shuffle_ps::<ZXY>(a, a), _mm_shuffle_ps::<YZX>(b, b)),
+            ))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Vector3;
+    use crate::scalar::scalar3;
+    use crate::test_util::{Rng, same_f32, same_f64};
+
+    fn v64(rng: &mut Rng) -> [f64; 3] {
+        [rng.wide_f64(), rng.wide_f64(), rng.wide_f64()]
+    }
+
+    fn v32(rng: &mut Rng) -> [f32; 3] {
+        v64(rng).map(|c| c as f32)
+    }
+
+    #[test]
+    fn test_simd_f64_matches_scalar() {
+        let mut rng = Rng::new(0x9e37_79b9_7f4a_7c15);
+        for _ in 0..100_000 {
+            let (a, b) = (v64(&mut rng), v64(&mut rng));
+            let pairs = [
+                (f64x3::add3(a, b), scalar3::add3(a, b)),
+                (f64x3::sub3(a, b), scalar3::sub3(a, b)),
+                (f64x3::mul3(a, b), scalar3::mul3(a, b)),
+                (f64x3::div3(a, b), scalar3::div3(a, b)),
+                (f64x3::cross3(a, b), scalar3::cross3(a, b)),
+            ];
+            for (simd, scalar) in pairs {
+                assert!(
+                    simd.iter().zip(&scalar).all(|(&s, &r)| same_f64(s, r)),
+                    "{a:?} {b:?}: {simd:?} != {scalar:?}"
+                );
+            }
+            let (simd, scalar) = (f64x3::dot3(a, b), scalar3::dot3(a, b));
+            assert!(same_f64(simd, scalar), "{a:?} . {b:?}: {simd} != {scalar}");
+        }
+    }
+
+    #[test]
+    fn test_simd_f32_matches_scalar() {
+        let mut rng = Rng::new(0x1234_5678_9abc_def1);
+        for _ in 0..100_000 {
+            let (a, b) = (v32(&mut rng), v32(&mut rng));
+            let pairs = [
+                (f32x3::add3(a, b), scalar3::add3(a, b)),
+                (f32x3::sub3(a, b), scalar3::sub3(a, b)),
+                (f32x3::mul3(a, b), scalar3::mul3(a, b)),
+                (f32x3::div3(a, b), scalar3::div3(a, b)),
+                (f32x3::cross3(a, b), scalar3::cross3(a, b)),
+            ];
+            for (simd, scalar) in pairs {
+                assert!(
+                    simd.iter().zip(&scalar).all(|(&s, &r)| same_f32(s, r)),
+                    "{a:?} {b:?}: {simd:?} != {scalar:?}"
+                );
+            }
+            let (simd, scalar) = (f32x3::dot3(a, b), scalar3::dot3(a, b));
+            assert!(same_f32(simd, scalar), "{a:?} . {b:?}: {simd} != {scalar}");
+        }
+    }
+
+    #[test]
+    fn test_simd_vector3_uses_kernels() {
+        let mut rng = Rng::new(42);
+        for _ in 0..10_000 {
+            let (a, b) = (v64(&mut rng), v64(&mut rng));
+            let (va, vb) = (Vector3::from(a), Vector3::from(b));
+            let length = scalar3::dot3(a, a).sqrt();
+            assert!(same_f64(va.length(), length));
+            let normalized = scalar3::div3(a, [length; 3]);
+            assert!(
+                <[f64; 3]>::from(va.normalized())
+                    .iter()
+                    .zip(&normalized)
+                    .all(|(&s, &r)| same_f64(s, r))
+            );
+            assert!(same_f64(va.dot(vb), scalar3::dot3(a, b)));
+        }
+    }
+}
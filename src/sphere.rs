@@ -0,0 +1,113 @@
+use crate::{Aabb, Ray, RayHit, UnitVector3, Vector3};
+
+/// A solid ball.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Sphere {
+    pub center: Vector3,
+    pub radius: f64,
+}
+
+impl Sphere {
+    pub fn new(center: Vector3, radius: f64) -> Self {
+        Self { center, radius }
+    }
+
+    pub fn aabb(self) -> Aabb {
+        let r = Vector3::ONE * self.radius;
+        Aabb::new(self.center - r, self.center + r)
+    }
+
+    /// Distance to the surface; negative inside.
+    pub fn signed_distance(self, point: Vector3) -> f64 {
+        (point - self.center).length() - self.radius
+    }
+
+    pub fn contains(self, point: Vector3) -> bool {
+        let offset = point - self.center;
+        offset.dot(offset) <= self.radius * self.radius
+    }
+
+    /// The nearest point of the ball, which is `point` itself if inside.
+    pub fn closest_point(self, point: Vector3) -> Vector3 {
+        if self.contains(point) {
+            return point;
+        }
+        self.center + (point - self.center).normalized() * self.radius
+    }
+
+    /// The first point where the ray crosses the surface, including exits
+    /// for rays that start inside.
+    pub fn intersect_ray(self, ray: Ray) -> Option<RayHit> {
+        let offset = ray.origin - self.center;
+        let b = offset.dot(*ray.direction);
+        let c = offset.dot(offset) - self.radius * self.radius;
+        let discriminant = b * b - c;
+        if discriminant < 0.0 {
+            return None;
+        }
+        let root = discriminant.sqrt();
+        let t = if -b - root >= 0.0 {
+            -b - root
+        } else {
+            -b + root
+        };
+        if t < 0.0 {
+            return None;
+        }
+        let point = ray.at(t);
+        let normal = UnitVector3::new_unchecked((point - self.center) / self.radius);
+        Some(RayHit {
+            distance: t,
+            point,
+            normal,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sphere_queries() {
+        let sphere = Sphere::new(Vector3::new(1.0, 0.0, 0.0), 2.0);
+        assert_eq!(sphere.signed_distance(Vector3::new(1.0, 5.0, 0.0)), 3.0);
+        assert_eq!(sphere.signed_distance(Vector3::new(1.0, 0.0, 0.0)), -2.0);
+        assert!(sphere.contains(Vector3::new(2.0, 1.0, 1.0)));
+        assert!(!sphere.contains(Vector3::new(3.0, 1.0, 0.0)));
+        assert_eq!(
+            sphere.closest_point(Vector3::new(1.0, 0.0, 5.0)),
+            Vector3::new(1.0, 0.0, 2.0)
+        );
+        let inside = Vector3::new(1.5, 0.0, 0.0);
+        assert_eq!(sphere.closest_point(inside), inside);
+    }
+
+    #[test]
+    fn test_sphere_intersect_ray() {
+        let sphere = Sphere::new(Vector3::new(0.0, 0.0, 10.0), 2.0);
+        let hit = sphere
+            .intersect_ray(Ray::new(Vector3::ZERO, UnitVector3::Z))
+            .unwrap();
+        assert_eq!(hit.distance, 8.0);
+        assert_eq!(hit.point, Vector3::new(0.0, 0.0, 8.0));
+        assert_eq!(*hit.normal, Vector3::new(0.0, 0.0, -1.0));
+
+        // Starting inside hits the far side.
+        let hit = sphere
+            .intersect_ray(Ray::new(Vector3::new(0.0, 0.0, 10.0), UnitVector3::Z))
+            .unwrap();
+        assert_eq!(hit.distance, 2.0);
+        assert_eq!(hit.normal, UnitVector3::Z);
+
+        // Miss, and sphere behind the ray.
+        assert_eq!(
+            sphere.intersect_ray(Ray::new(Vector3::new(0.0, 3.0, 0.0), UnitVector3::Z)),
+            None
+        );
+        assert_eq!(
+            sphere.intersect_ray(Ray::new(Vector3::new(0.0, 0.0, 20.0), UnitVector3::Z)),
+            None
+        );
+    }
+}
@@ -0,0 +1,67 @@
+//! Helpers shared by the unit tests.
+
+// Some helpers are only used under particular feature combinations.
+#![allow(dead_code)]
+
+use crate::Vector3;
+
+/// xorshift64*, so the tests need no extra dependencies.
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self(seed.max(1))
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 >> 12;
+        self.0 ^= self.0 << 25;
+        self.0 ^= self.0 >> 27;
+        self.0.wrapping_mul(0x2545_f491_4f6c_dd1d)
+    }
+
+    /// Uniform in `[0, 1)`.
+    pub fn unit(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    pub fn range(&mut self, low: f64, high: f64) -> f64 {
+        low + (high - low) * self.unit()
+    }
+
+    /// Uniform in the cube `[low, high]^3`.
+    pub fn vector(&mut self, low: f64, high: f64) -> Vector3 {
+        Vector3::new(
+            self.range(low, high),
+            self.range(low, high),
+            self.range(low, high),
+        )
+    }
+
+    /// Values spread over many magnitudes, with occasional special values.
+    pub fn wide_f64(&mut self) -> f64 {
+        let bits = self.next_u64();
+        match bits % 64 {
+            0 => 0.0,
+            1 => -0.0,
+            2 => f64::INFINITY,
+            3 => f64::NAN,
+            4 => f64::MIN_POSITIVE / 3.0,
+            _ => {
+                let mantissa = (bits >> 11) as f64 / (1u64 << 53) as f64 - 0.5;
+                let exponent = (bits % 81) as i32 - 40;
+                mantissa * 2f64.powi(exponent)
+            }
+        }
+    }
+}
+
+/// Bitwise equality that treats all NaNs as equal.
+pub fn same_f64(a: f64, b: f64) -> bool {
+    a.to_bits() == b.to_bits() || (a.is_nan() && b.is_nan())
+}
+
+/// Bitwise equality that treats all NaNs as equal.
+pub fn same_f32(a: f32, b: f32) -> bool {
+    a.to_bits() == b.to_bits() || (a.is_nan() && b.is_nan())
+}
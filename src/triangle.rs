@@ -0,0 +1,255 @@
+use crate::{Aabb, GeometryError, Plane, Ray, RayHit, UnitVector3, Vector3};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Triangle {
+    pub a: Vector3,
+    pub b: Vector3,
+    pub c: Vector3,
+}
+
+impl Triangle {
+    pub fn new(a: Vector3, b: Vector3, c: Vector3) -> Self {
+        Self { a, b, c }
+    }
+
+    /// The normal following the right-hand rule for `a`, `b`, `c`.
+    pub fn normal(self) -> Result<UnitVector3, GeometryError> {
+        UnitVector3::try_new((self.b - self.a).cross(self.c - self.a))
+    }
+
+    pub fn plane(self) -> Result<Plane, GeometryError> {
+        Ok(Plane::from_point_normal(self.a, self.normal()?))
+    }
+
+    pub fn area(self) -> f64 {
+        (self.b - self.a).cross(self.c - self.a).length() * 0.5
+    }
+
+    pub fn centroid(self) -> Vector3 {
+        (self.a + self.b + self.c) / 3.0
+    }
+
+    pub fn aabb(self) -> Aabb {
+        Aabb::from_points([self.a, self.b, self.c])
+    }
+
+    /// The nearest point of the triangle, found by classifying `point`
+    /// against the Voronoi regions of the vertices, edges and face.
+    pub fn closest_point(self, point: Vector3) -> Vector3 {
+        let (a, b, c) = (self.a, self.b, self.c);
+        let ab = b - a;
+        let ac = c - a;
+        // Degenerate triangles would divide by zero below; they are covered
+        // by their edges.
+        let normal = ab.cross(ac);
+        if normal.dot(normal) <= f64::EPSILON * ab.dot(ab) * ac.dot(ac) {
+            let distance = |q: Vector3| (point - q).dot(point - q);
+            return [(a, b), (b, c), (c, a)]
+                .map(|(start, end)| closest_point_on_segment(start, end, point))
+                .into_iter()
+                .min_by(|p, q| distance(*p).total_cmp(&distance(*q)))
+                .unwrap_or(a);
+        }
+        let ap = point - a;
+        let d1 = ab.dot(ap);
+        let d2 = ac.dot(ap);
+        if d1 <= 0.0 && d2 <= 0.0 {
+            return a;
+        }
+
+        let bp = point - b;
+        let d3 = ab.dot(bp);
+        let d4 = ac.dot(bp);
+        if d3 >= 0.0 && d4 <= d3 {
+            return b;
+        }
+
+        let vc = d1 * d4 - d3 * d2;
+        if vc <= 0.0 && d1 >= 0.0 && d3 <= 0.0 {
+            return a + ab * (d1 / (d1 - d3));
+        }
+
+        let cp = point - c;
+        let d5 = ab.dot(cp);
+        let d6 = ac.dot(cp);
+        if d6 >= 0.0 && d5 <= d6 {
+            return c;
+        }
+
+        let vb = d5 * d2 - d1 * d6;
+        if vb <= 0.0 && d2 >= 0.0 && d6 <= 0.0 {
+            return a + ac * (d2 / (d2 - d6));
+        }
+
+        let va = d3 * d6 - d5 * d4;
+        if va <= 0.0 && d4 - d3 >= 0.0 && d5 - d6 >= 0.0 {
+            return b + (c - b) * ((d4 - d3) / ((d4 - d3) + (d5 - d6)));
+        }
+
+        let denominator = 1.0 / (va + vb + vc);
+        a + ab * (vb * denominator) + ac * (vc * denominator)
+    }
+
+    pub fn distance(self, point: Vector3) -> f64 {
+        (point - self.closest_point(point)).length()
+    }
+
+    /// Möller–Trumbore intersection. Both sides are hit; degenerate
+    /// triangles are never hit.
+    pub fn intersect_ray(self, ray: Ray) -> Option<RayHit> {
+        let e1 = self.b - self.a;
+        let e2 = self.c - self.a;
+        let p = ray.direction.cross(e2);
+        let determinant = e1.dot(p);
+        if determinant == 0.0 {
+            return None;
+        }
+        let inverse = 1.0 / determinant;
+        let s = ray.origin - self.a;
+        let u = s.dot(p) * inverse;
+        if !(0.0..=1.0).contains(&u) {
+            return None;
+        }
+        let q = s.cross(e1);
+        let v = ray.direction.dot(q) * inverse;
+        if v < 0.0 || u + v > 1.0 {
+            return None;
+        }
+        let t = e2.dot(q) * inverse;
+        if t < 0.0 {
+            return None;
+        }
+        let normal = self.normal().ok()?;
+        let normal = if normal.dot(*ray.direction) > 0.0 {
+            -normal
+        } else {
+            normal
+        };
+        Some(ray.hit(t, normal))
+    }
+}
+
+/// The nearest point of the segment from `start` to `end`.
+fn closest_point_on_segment(start: Vector3, end: Vector3, point: Vector3) -> Vector3 {
+    let d = end - start;
+    let length_squared = d.dot(d);
+    if length_squared == 0.0 {
+        return start;
+    }
+    let t = (point - start).dot(d) / length_squared;
+    start + d * t.clamp(0.0, 1.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn triangle() -> Triangle {
+        Triangle::new(
+            Vector3::new(0.0, 0.0, 0.0),
+            Vector3::new(4.0, 0.0, 0.0),
+            Vector3::new(0.0, 4.0, 0.0),
+        )
+    }
+
+    #[test]
+    fn test_triangle_properties() {
+        let t = triangle();
+        assert_eq!(t.normal(), Ok(UnitVector3::Z));
+        assert_eq!(t.area(), 8.0);
+        assert_eq!(t.plane().unwrap().d, 0.0);
+        assert_eq!(t.aabb().max, Vector3::new(4.0, 4.0, 0.0));
+
+        let degenerate = Triangle::new(Vector3::ZERO, Vector3::ONE, Vector3::ONE * 2.0);
+        assert_eq!(degenerate.normal(), Err(GeometryError::DegenerateVector));
+    }
+
+    #[test]
+    fn test_triangle_closest_point() {
+        let t = triangle();
+        // Face, vertex and edge regions.
+        assert_eq!(
+            t.closest_point(Vector3::new(1.0, 1.0, 5.0)),
+            Vector3::new(1.0, 1.0, 0.0)
+        );
+        assert_eq!(t.closest_point(Vector3::new(-1.0, -1.0, 0.0)), t.a);
+        assert_eq!(t.closest_point(Vector3::new(6.0, -1.0, 0.0)), t.b);
+        assert_eq!(t.closest_point(Vector3::new(-1.0, 9.0, 2.0)), t.c);
+        assert_eq!(
+            t.closest_point(Vector3::new(2.0, -3.0, 0.0)),
+            Vector3::new(2.0, 0.0, 0.0)
+        );
+        assert_eq!(
+            t.closest_point(Vector3::new(-3.0, 2.0, 1.0)),
+            Vector3::new(0.0, 2.0, 0.0)
+        );
+        assert_eq!(
+            t.closest_point(Vector3::new(3.0, 3.0, 0.0)),
+            Vector3::new(2.0, 2.0, 0.0)
+        );
+        assert_eq!(t.distance(Vector3::new(2.0, -3.0, 4.0)), 5.0);
+    }
+
+    #[test]
+    fn test_triangle_closest_point_degenerate() {
+        // Collinear, with `c` between `a` and `b`.
+        let t = Triangle::new(
+            Vector3::new(0.0, 0.0, 0.0),
+            Vector3::new(4.0, 0.0, 0.0),
+            Vector3::new(2.0, 0.0, 0.0),
+        );
+        for (point, expected) in [
+            (Vector3::new(1.0, 1.0, 0.0), Vector3::new(1.0, 0.0, 0.0)),
+            (Vector3::new(3.0, -2.0, 5.0), Vector3::new(3.0, 0.0, 0.0)),
+            (Vector3::new(6.0, 1.0, 0.0), t.b),
+            (Vector3::new(-1.0, 0.0, 0.0), t.a),
+        ] {
+            assert_eq!(t.closest_point(point), expected);
+        }
+
+        // Collinear after rounding, and with repeated or coincident vertices.
+        let d = Vector3::new(0.1, 0.2, 0.3);
+        let t = Triangle::new(Vector3::ZERO, d * 3.0, d);
+        let point = d * 2.0 + Vector3::new(0.0, 0.0, 1e-3);
+        assert!((t.closest_point(point) - d * 2.0).length() < 1e-3);
+        let t = Triangle::new(Vector3::ZERO, Vector3::ZERO, Vector3::new(2.0, 0.0, 0.0));
+        assert_eq!(
+            t.closest_point(Vector3::new(1.0, 1.0, 1.0)),
+            Vector3::new(1.0, 0.0, 0.0)
+        );
+        let t = Triangle::new(Vector3::ONE, Vector3::ONE, Vector3::ONE);
+        assert_eq!(t.closest_point(Vector3::ZERO), Vector3::ONE);
+    }
+
+    #[test]
+    fn test_triangle_intersect_ray() {
+        let t = triangle();
+        let down = -UnitVector3::Z;
+        let hit = t
+            .intersect_ray(Ray::new(Vector3::new(1.0, 1.0, 3.0), down))
+            .unwrap();
+        assert_eq!(hit.distance, 3.0);
+        assert_eq!(hit.point, Vector3::new(1.0, 1.0, 0.0));
+        assert_eq!(hit.normal, UnitVector3::Z);
+
+        // The back face is hit too, with the normal facing the ray.
+        let hit = t
+            .intersect_ray(Ray::new(Vector3::new(1.0, 1.0, -3.0), UnitVector3::Z))
+            .unwrap();
+        assert_eq!(hit.normal, down);
+
+        // Outside the edges, parallel, and behind.
+        assert_eq!(
+            t.intersect_ray(Ray::new(Vector3::new(3.0, 3.0, 3.0), down)),
+            None
+        );
+        assert_eq!(
+            t.intersect_ray(Ray::new(Vector3::new(1.0, 1.0, 1.0), UnitVector3::X)),
+            None
+        );
+        assert_eq!(
+            t.intersect_ray(Ray::new(Vector3::new(1.0, 1.0, -1.0), down)),
+            None
+        );
+    }
+}
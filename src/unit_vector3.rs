@@ -0,0 +1,195 @@
+use std::ops::{Deref, Mul, Neg};
+
+use crate::{Float, GeometryError, Quaternion, Scalar, Vector3};
+
+/// A `Vector3` that is known to have unit length.
+///
+/// Functions that need a direction can take a `UnitVector3` instead of
+/// checking or normalizing their input.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[repr(transparent)]
+pub struct UnitVector3<T = f64>(Vector3<T>);
+
+impl<T: Scalar> UnitVector3<T> {
+    pub const X: Self = Self(Vector3 {
+        x: T::ONE,
+        y: T::ZERO,
+        z: T::ZERO,
+    });
+
+    pub const Y: Self = Self(Vector3 {
+        x: T::ZERO,
+        y: T::ONE,
+        z: T::ZERO,
+    });
+
+    pub const Z: Self = Self(Vector3 {
+        x: T::ZERO,
+        y: T::ZERO,
+        z: T::ONE,
+    });
+
+    /// Wraps `v` without checking its length. The caller is responsible for
+    /// `v` being normalized.
+    pub fn new_unchecked(v: Vector3<T>) -> Self {
+        Self(v)
+    }
+
+    pub fn into_inner(self) -> Vector3<T> {
+        self.0
+    }
+}
+
+impl<T: Float> UnitVector3<T> {
+    pub fn try_new(v: Vector3<T>) -> Result<Self, GeometryError> {
+        v.try_normalized().map(Self)
+    }
+
+    /// Angle in radians between two directions, without normalizing either.
+    pub fn angle(self, other: Self) -> T {
+        let d = self.0.dot(other.0);
+        // Rounding can push the dot product of unit vectors slightly past 1.
+        let d = if d > T::ONE {
+            T::ONE
+        } else if d < -T::ONE {
+            -T::ONE
+        } else {
+            d
+        };
+        d.acos()
+    }
+
+    /// Pulls a unit vector that has drifted slightly from unit length, e.g.
+    /// through repeated rotation, back onto the unit sphere with one Newton
+    /// step, avoiding a square root.
+    ///
+    /// Vectors further than about 0.1% from unit length are normalized in
+    /// full instead, as the Newton step only converges near the sphere.
+    /// Arbitrary vectors should go through `try_new`.
+    pub fn renormalize(self) -> Self {
+        let v = self.0;
+        let two = T::ONE + T::ONE;
+        let three = two + T::ONE;
+        let sixteen = two * two * two * two;
+        let band = T::ONE / (sixteen * sixteen * two * two);
+        let squared = v.dot(v);
+        let drift = squared - T::ONE;
+        if drift < band && -drift < band {
+            Self(v * ((three - squared) / two))
+        } else {
+            Self(v.normalized())
+        }
+    }
+}
+
+impl<T> Deref for UnitVector3<T> {
+    type Target = Vector3<T>;
+
+    fn deref(&self) -> &Vector3<T> {
+        &self.0
+    }
+}
+
+impl<T: Float> TryFrom<Vector3<T>> for UnitVector3<T> {
+    type Error = GeometryError;
+
+    fn try_from(v: Vector3<T>) -> Result<Self, GeometryError> {
+        Self::try_new(v)
+    }
+}
+
+impl<T> From<UnitVector3<T>> for Vector3<T> {
+    fn from(v: UnitVector3<T>) -> Self {
+        v.0
+    }
+}
+
+impl<T: Scalar> Neg for UnitVector3<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self(Vector3::new(-self.0.x, -self.0.y, -self.0.z))
+    }
+}
+
+impl Mul<UnitVector3> for Quaternion {
+    type Output = UnitVector3;
+
+    fn mul(self, other: UnitVector3) -> UnitVector3 {
+        UnitVector3(self.rotate(other.0)).renormalize()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f64::consts::FRAC_PI_2;
+
+    #[test]
+    fn test_unit_vector_try_new() {
+        let v = UnitVector3::try_new(Vector3::new(0.0, 3.0, 4.0)).unwrap();
+        assert_eq!(*v, Vector3::new(0.0, 0.6, 0.8));
+        assert_eq!(v.x, 0.0);
+        assert_eq!(
+            UnitVector3::try_new(Vector3::<f64>::ZERO),
+            Err(GeometryError::DegenerateVector)
+        );
+        assert_eq!(
+            UnitVector3::try_from(Vector3::new(f64::NAN, 1.0, 0.0)),
+            Err(GeometryError::NonFiniteComponent)
+        );
+    }
+
+    #[test]
+    fn test_unit_vector_deref() {
+        let v: UnitVector3 = UnitVector3::X;
+        assert_eq!(v.length(), 1.0);
+        assert_eq!(v.cross(*UnitVector3::Y), *UnitVector3::Z);
+        assert_eq!(Vector3::from(-v), Vector3::new(-1.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn test_unit_vector_angle() {
+        let x: UnitVector3 = UnitVector3::X;
+        assert_eq!(x.angle(UnitVector3::Y), FRAC_PI_2);
+        assert_eq!(x.angle(x), 0.0);
+        // Slightly overlong input must not produce NaN.
+        let almost = UnitVector3::new_unchecked(Vector3::new(1.0 + 1e-15, 0.0, 0.0));
+        assert_eq!(almost.angle(x), 0.0);
+    }
+
+    #[test]
+    fn test_unit_vector_renormalize() {
+        let drifted = Vector3::new(0.6, 0.8, 0.0) * (1.0 + 1e-6);
+        let v = UnitVector3::new_unchecked(drifted).renormalize();
+        assert!((v.length() - 1.0).abs() < 1e-11);
+
+        // Far from unit length the Newton step would overshoot, even flipping
+        // the direction; these are normalized in full.
+        for v in [
+            Vector3::new(3.0, 0.0, 0.0),
+            Vector3::new(0.0, 0.1, 0.0),
+            Vector3::new(1.0, 1.0, 1.0),
+        ] {
+            let unit = UnitVector3::new_unchecked(v).renormalize();
+            assert_vec_approx_eq!(*unit, v.normalized());
+        }
+        let f = UnitVector3::new_unchecked(Vector3::<f32>::new(0.0, 0.0, -2.0)).renormalize();
+        assert_eq!(*f, Vector3::new(0.0, 0.0, -1.0));
+    }
+
+    #[test]
+    fn test_unit_vector_rotate() {
+        let q = Quaternion::from_axis_angle(Vector3::new(1.0, 1.0, 1.0), 1.0);
+        let mut v = UnitVector3::X;
+        for _ in 0..10_000 {
+            v = q * v;
+        }
+        assert!((v.length() - 1.0).abs() < 1e-15);
+
+        // A non-unit quaternion distorts the rotation, but the result must
+        // still be a unit vector.
+        let scaled = Quaternion::from_axis_angle(Vector3::new(0.0, 0.0, 1.0), 1.0) * 2.0;
+        assert_vec_approx_eq!((scaled * UnitVector3::X).length(), 1.0);
+    }
+}
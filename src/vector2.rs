@@ -0,0 +1,168 @@
+use std::ops::{Add, Div, Mul, Sub};
+
+use crate::{Float, Scalar, Vector3};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[repr(C)]
+pub struct Vector2<T = f64> {
+    pub x: T,
+    pub y: T,
+}
+
+pub type Vec2f = Vector2<f32>;
+pub type Vec2d = Vector2<f64>;
+pub type Vec2i = Vector2<i32>;
+
+impl<T: Scalar> Vector2<T> {
+    pub const ZERO: Self = Self {
+        x: T::ZERO,
+        y: T::ZERO,
+    };
+
+    pub const ONE: Self = Self {
+        x: T::ONE,
+        y: T::ONE,
+    };
+
+    pub fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+
+    pub fn dot(self, other: Self) -> T {
+        self.x * other.x + self.y * other.y
+    }
+
+    pub fn extend(self, z: T) -> Vector3<T> {
+        Vector3::new(self.x, self.y, z)
+    }
+}
+
+impl<T: Float> Vector2<T> {
+    pub fn length(self) -> T {
+        self.dot(self).sqrt()
+    }
+
+    pub fn normalized(self) -> Self {
+        self / self.length()
+    }
+}
+
+impl<T: Scalar> Add for Vector2<T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self {
+            x: self.x + other.x,
+            y: self.y + other.y,
+        }
+    }
+}
+
+impl<T: Scalar> Sub for Vector2<T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self {
+            x: self.x - other.x,
+            y: self.y - other.y,
+        }
+    }
+}
+
+impl<T: Scalar> Mul<T> for Vector2<T> {
+    type Output = Self;
+
+    fn mul(self, other: T) -> Self {
+        Self {
+            x: self.x * other,
+            y: self.y * other,
+        }
+    }
+}
+
+impl<T: Scalar> Mul for Vector2<T> {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        Self {
+            x: self.x * other.x,
+            y: self.y * other.y,
+        }
+    }
+}
+
+impl<T: Float> Div<T> for Vector2<T> {
+    type Output = Self;
+
+    fn div(self, other: T) -> Self {
+        Self {
+            x: self.x / other,
+            y: self.y / other,
+        }
+    }
+}
+
+impl<T: Float> Div for Vector2<T> {
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self {
+        Self {
+            x: self.x * (T::ONE / other.x),
+            y: self.y * (T::ONE / other.y),
+        }
+    }
+}
+
+impl_scalar_lhs_mul!(Vector2 { x, y }; f32, f64, i8, i16, i32, i64, i128, isize);
+impl_scalar_lhs_div!(Vector2 { x, y }; f32, f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Vec3i;
+
+    #[test]
+    fn test_vector2_new() {
+        let v = Vector2::new(1.0, 2.0);
+        assert_eq!(v.x, 1.0);
+        assert_eq!(v.y, 2.0);
+        assert_eq!(Vec2d::ZERO, Vector2::new(0.0, 0.0));
+        assert_eq!(Vec2d::ONE, Vector2::new(1.0, 1.0));
+    }
+
+    #[test]
+    fn test_vector2_dot() {
+        let v1 = Vector2::new(1.0, 2.0);
+        let v2 = Vector2::new(3.0, 4.0);
+        assert_eq!(v1.dot(v2), 11.0);
+    }
+
+    #[test]
+    fn test_vector2_length() {
+        let v = Vector2::new(3.0, 4.0);
+        assert_eq!(v.length(), 5.0);
+        assert_eq!(v.normalized(), Vector2::new(0.6, 0.8));
+    }
+
+    #[test]
+    fn test_vector2_ops() {
+        let v1: Vec2d = Vector2::new(2.0, 4.0);
+        let v2 = Vector2::new(1.0, 2.0);
+        assert_eq!(v1 + v2, Vector2::new(3.0, 6.0));
+        assert_eq!(v1 - v2, Vector2::new(1.0, 2.0));
+        assert_eq!(v1 * 2.0, Vector2::new(4.0, 8.0));
+        assert_eq!(2.0 * v1, Vector2::new(4.0, 8.0));
+        assert_eq!(v1 * v2, Vector2::new(2.0, 8.0));
+        assert_eq!(v1 / 2.0, Vector2::new(1.0, 2.0));
+        assert_eq!(4.0 / v1, Vector2::new(2.0, 1.0));
+        assert_eq!(v1 / v2, Vector2::new(2.0, 2.0));
+    }
+
+    #[test]
+    fn test_vector2_integer() {
+        let v = Vec2i::new(1, 2);
+        assert_eq!(v.dot(v), 5);
+        assert_eq!(3 * v, Vec2i::new(3, 6));
+        assert_eq!(v.extend(3), Vec3i::new(1, 2, 3));
+    }
+}
@@ -0,0 +1,186 @@
+use std::ops::{Add, Div, Mul, Sub};
+
+use crate::{Float, Scalar, Vector3};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[repr(C)]
+pub struct Vector4<T = f64> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+    pub w: T,
+}
+
+pub type Vec4f = Vector4<f32>;
+pub type Vec4d = Vector4<f64>;
+pub type Vec4i = Vector4<i32>;
+
+impl<T: Scalar> Vector4<T> {
+    pub const ZERO: Self = Self {
+        x: T::ZERO,
+        y: T::ZERO,
+        z: T::ZERO,
+        w: T::ZERO,
+    };
+
+    pub const ONE: Self = Self {
+        x: T::ONE,
+        y: T::ONE,
+        z: T::ONE,
+        w: T::ONE,
+    };
+
+    pub fn new(x: T, y: T, z: T, w: T) -> Self {
+        Self { x, y, z, w }
+    }
+
+    pub fn dot(self, other: Self) -> T {
+        self.x * other.x + self.y * other.y + self.z * other.z + self.w * other.w
+    }
+
+    pub fn truncate(self) -> Vector3<T> {
+        Vector3::new(self.x, self.y, self.z)
+    }
+}
+
+impl<T: Float> Vector4<T> {
+    pub fn length(self) -> T {
+        self.dot(self).sqrt()
+    }
+
+    pub fn normalized(self) -> Self {
+        self / self.length()
+    }
+}
+
+impl<T: Scalar> Add for Vector4<T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self {
+            x: self.x + other.x,
+            y: self.y + other.y,
+            z: self.z + other.z,
+            w: self.w + other.w,
+        }
+    }
+}
+
+impl<T: Scalar> Sub for Vector4<T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self {
+            x: self.x - other.x,
+            y: self.y - other.y,
+            z: self.z - other.z,
+            w: self.w - other.w,
+        }
+    }
+}
+
+impl<T: Scalar> Mul<T> for Vector4<T> {
+    type Output = Self;
+
+    fn mul(self, other: T) -> Self {
+        Self {
+            x: self.x * other,
+            y: self.y * other,
+            z: self.z * other,
+            w: self.w * other,
+        }
+    }
+}
+
+impl<T: Scalar> Mul for Vector4<T> {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        Self {
+            x: self.x * other.x,
+            y: self.y * other.y,
+            z: self.z * other.z,
+            w: self.w * other.w,
+        }
+    }
+}
+
+impl<T: Float> Div<T> for Vector4<T> {
+    type Output = Self;
+
+    fn div(self, other: T) -> Self {
+        Self {
+            x: self.x / other,
+            y: self.y / other,
+            z: self.z / other,
+            w: self.w / other,
+        }
+    }
+}
+
+impl<T: Float> Div for Vector4<T> {
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self {
+        Self {
+            x: self.x * (T::ONE / other.x),
+            y: self.y * (T::ONE / other.y),
+            z: self.z * (T::ONE / other.z),
+            w: self.w * (T::ONE / other.w),
+        }
+    }
+}
+
+impl_scalar_lhs_mul!(Vector4 { x, y, z, w }; f32, f64, i8, i16, i32, i64, i128, isize);
+impl_scalar_lhs_div!(Vector4 { x, y, z, w }; f32, f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vector4_new() {
+        let v = Vector4::new(1.0, 2.0, 3.0, 4.0);
+        assert_eq!(v.x, 1.0);
+        assert_eq!(v.y, 2.0);
+        assert_eq!(v.z, 3.0);
+        assert_eq!(v.w, 4.0);
+        assert_eq!(Vec4d::ZERO, Vector4::new(0.0, 0.0, 0.0, 0.0));
+        assert_eq!(Vec4d::ONE, Vector4::new(1.0, 1.0, 1.0, 1.0));
+    }
+
+    #[test]
+    fn test_vector4_dot() {
+        let v1 = Vector4::new(1.0, 2.0, 3.0, 4.0);
+        let v2 = Vector4::new(5.0, 6.0, 7.0, 8.0);
+        assert_eq!(v1.dot(v2), 70.0);
+    }
+
+    #[test]
+    fn test_vector4_length() {
+        let v = Vector4::new(1.0, 1.0, 1.0, 1.0);
+        assert_eq!(v.length(), 2.0);
+        assert_eq!(v.normalized(), Vector4::new(0.5, 0.5, 0.5, 0.5));
+    }
+
+    #[test]
+    fn test_vector4_ops() {
+        let v1: Vec4d = Vector4::new(2.0, 4.0, 6.0, 8.0);
+        let v2 = Vector4::new(1.0, 2.0, 3.0, 4.0);
+        assert_eq!(v1 + v2, Vector4::new(3.0, 6.0, 9.0, 12.0));
+        assert_eq!(v1 - v2, Vector4::new(1.0, 2.0, 3.0, 4.0));
+        assert_eq!(v1 * 2.0, Vector4::new(4.0, 8.0, 12.0, 16.0));
+        assert_eq!(2.0 * v1, Vector4::new(4.0, 8.0, 12.0, 16.0));
+        assert_eq!(v1 * v2, Vector4::new(2.0, 8.0, 18.0, 32.0));
+        assert_eq!(v1 / 2.0, Vector4::new(1.0, 2.0, 3.0, 4.0));
+        assert_eq!(8.0 / v1, Vector4::new(4.0, 2.0, 8.0 / 6.0, 1.0));
+        assert_eq!(v1 / v2, Vector4::new(2.0, 2.0, 2.0, 2.0));
+    }
+
+    #[test]
+    fn test_vector4_truncate() {
+        let v = Vec4i::new(1, 2, 3, 4);
+        assert_eq!(v.truncate(), Vector3::new(1, 2, 3));
+        assert_eq!(v.truncate().extend(4), v);
+    }
+}